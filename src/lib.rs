@@ -6,3 +6,6 @@ pub mod utils;
 
 /// States
 pub mod states;
+
+/// Models
+pub mod models;
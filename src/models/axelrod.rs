@@ -0,0 +1,171 @@
+//! Axelrod Model
+//!
+//! Dissemination of culture where each site carries $F$ features with $q$ traits each,
+//! neighbors interacting with a probability given by their cultural overlap.
+//! Ordered monocultural states turn into fragmented ones above a critical $q$.
+//!
+//! Axelrod, J. Conflict Resolut. 41, 203 (1997),
+//! Castellano, Marsili & Vespignani, Phys. Rev. Lett. 85, 3536 (2000)
+
+use super::MonteCarloUpdate;
+use crate::states::{SiteStateNN, StateMeasurement, clusters::Clusters};
+use rand::{Rng, seq::IteratorRandom};
+use rand_distr::Distribution;
+
+/// Number of features shared by two cultures
+#[inline(always)]
+pub fn overlap<const F: usize>(a: &[u8; F], b: &[u8; F]) -> usize {
+    a.iter().zip(b).filter(|(x, y)| x == y).count()
+}
+
+/// Axelrod update over sites holding cultures `[u8; F]` with traits in `0..q`
+/// Each event selects a random site and a random nearest neighbor, which with probability
+/// given by their overlap fraction copies one of the neighbor's differing features.
+/// With probability `noise` an event instead sets a random feature of the site to a random trait.
+///
+/// A Monte Carlo step consists of $N$ events.
+#[derive(Debug, Clone, Copy)]
+pub struct Axelrod {
+    /// Number of traits $q$ of each feature
+    pub q: u8,
+    /// Cultural drift rate $r$
+    pub noise: f64,
+}
+
+impl Axelrod {
+    /// Create update with $q$ traits per feature without noise
+    ///
+    /// Panics if `q` is zero.
+    pub fn new(q: u8) -> Self {
+        assert!(q > 0, "Features must have at least one trait");
+        Self { q, noise: 0.0 }
+    }
+
+    /// Use a given cultural drift rate $r$
+    pub fn with_noise(mut self, noise: f64) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    /// Perform a single event
+    fn event<S, R, const F: usize>(&self, state: &mut S, rng: &mut R)
+    where
+        S: SiteStateNN<Site = [u8; F]>,
+        R: Rng + ?Sized,
+    {
+        let idx = state.sample(rng);
+        // Cultural drift
+        if self.noise > 0.0 && rng.r#gen::<f64>() < self.noise {
+            let feature = rng.gen_range(0..F);
+            state[idx][feature] = rng.gen_range(0..self.q);
+            return;
+        }
+        let Some(nn_idx) = state.nearest_neighbors_index(idx).choose(rng) else {
+            return;
+        };
+        let (culture, nn_culture) = (state[idx], state[nn_idx]);
+        let shared = overlap(&culture, &nn_culture);
+        if shared == F || rng.gen_range(0..F) >= shared {
+            return;
+        }
+        // Copy a differing feature
+        if let Some(feature) = (0..F).filter(|&f| culture[f] != nn_culture[f]).choose(rng) {
+            state[idx][feature] = nn_culture[feature];
+        }
+    }
+}
+
+impl<S, const F: usize> MonteCarloUpdate<S> for Axelrod
+where
+    S: SiteStateNN<Site = [u8; F]>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            self.event(state, rng);
+        }
+    }
+}
+
+/// Uniformly random culture with $q$ traits per feature
+impl<const F: usize> Distribution<[u8; F]> for Axelrod {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u8; F] {
+        std::array::from_fn(|_| rng.gen_range(0..self.q))
+    }
+}
+
+/// Cultural regions: clusters of nearest neighbors sharing the same culture
+#[derive(Debug, Clone, Copy)]
+pub struct CulturalRegions;
+
+impl<S, const F: usize> StateMeasurement<S> for CulturalRegions
+where
+    S: SiteStateNN<Site = [u8; F]>,
+{
+    type Type = Clusters;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        Clusters::identify(state, |a, b| a == b)
+    }
+}
+
+/// Fraction of sites in the largest cultural region $\langle S_{max} \rangle / N$
+#[derive(Debug, Clone, Copy)]
+pub struct LargestRegion;
+
+impl<S, const F: usize> StateMeasurement<S> for LargestRegion
+where
+    S: SiteStateNN<Site = [u8; F]>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        CulturalRegions.measure(state).largest() as f64 / state.site_count() as f64
+    }
+}
+
+/// Density of active bonds: nearest neighbors pairs sharing some but not all features
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveBonds;
+
+impl<S, const F: usize> StateMeasurement<S> for ActiveBonds
+where
+    S: SiteStateNN<Site = [u8; F]>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let (mut active, mut bonds) = (0, 0);
+        for (a, b) in state.nearest_neighbors_pairs() {
+            let shared = overlap(a, b);
+            active += (shared > 0 && shared < F) as usize;
+            bonds += 1;
+        }
+        if bonds > 0 {
+            active as f64 / bonds as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Run a noiseless update until no active bonds remain or for at most `max_steps` Monte Carlo steps
+/// Returns the freezing time in Monte Carlo steps, if reached
+pub fn run_until_frozen<S, R, const F: usize>(
+    update: &mut Axelrod,
+    state: &mut S,
+    max_steps: usize,
+    rng: &mut R,
+) -> Option<usize>
+where
+    S: SiteStateNN<Site = [u8; F]>,
+    R: Rng + ?Sized,
+{
+    for t in 0..max_steps {
+        if ActiveBonds.measure(state) == 0.0 {
+            return Some(t);
+        }
+        update.step(state, rng);
+    }
+    (ActiveBonds.measure(state) == 0.0).then_some(max_steps)
+}
@@ -0,0 +1,143 @@
+//! Bounded Confidence Opinion Dynamics
+//!
+//! Continuous opinions in `f64` sites interacting only when closer than a confidence bound $\epsilon$,
+//! converging to one or several opinion clusters depending on $\epsilon$.
+//!
+//! Deffuant, Neau, Amblard & Weisbuch, Adv. Complex Syst. 3, 87 (2000),
+//! Hegselmann & Krause, J. Artif. Soc. Soc. Simul. 5, 2 (2002)
+
+use super::MonteCarloUpdate;
+use crate::states::{SiteState, SiteStateNN, StateMeasurement};
+use rand::{Rng, seq::IteratorRandom};
+
+/// Deffuant update
+/// Each event selects a random site and a random nearest neighbor, which move their opinions
+/// towards each other by a fraction $\mu$ of their difference if it is smaller than $\epsilon$.
+///
+/// A Monte Carlo step consists of $N$ events.
+#[derive(Debug, Clone, Copy)]
+pub struct Deffuant {
+    /// Confidence bound $\epsilon$
+    pub confidence: f64,
+    /// Convergence parameter $\mu \in (0, 1/2]$
+    pub convergence: f64,
+}
+
+impl<S> MonteCarloUpdate<S> for Deffuant
+where
+    S: SiteStateNN<Site = f64>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            let Some(nn_idx) = state.nearest_neighbors_index(idx).choose(rng) else {
+                continue;
+            };
+            let difference = state[nn_idx] - state[idx];
+            if difference.abs() < self.confidence {
+                state[idx] += self.convergence * difference;
+                state[nn_idx] -= self.convergence * difference;
+            }
+        }
+    }
+}
+
+/// Hegselmann–Krause update
+/// Each Monte Carlo step synchronously replaces every opinion by the average of its own
+/// and its nearest neighbors' opinions closer to it than the confidence bound $\epsilon$.
+#[derive(Debug, Clone, Copy)]
+pub struct HegselmannKrause {
+    /// Confidence bound $\epsilon$
+    pub confidence: f64,
+}
+
+impl<S> MonteCarloUpdate<S> for HegselmannKrause
+where
+    S: SiteStateNN<Site = f64>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, _rng: &mut R) {
+        let opinions: Vec<f64> = state
+            .indices()
+            .map(|idx| {
+                let opinion = state[idx];
+                let (sum, count) = state
+                    .nearest_neighbors(idx)
+                    .filter(|&&x| (x - opinion).abs() < self.confidence)
+                    .fold((opinion, 1), |(sum, count), &x| (sum + x, count + 1));
+                sum / count as f64
+            })
+            .collect();
+        for (site, opinion) in state.sites_mut().zip(opinions) {
+            *site = opinion;
+        }
+    }
+}
+
+/// Run an update until no opinion changes by more than `tolerance` in a Monte Carlo step
+/// or for at most `max_steps` Monte Carlo steps
+/// Returns the convergence time in Monte Carlo steps, if reached
+pub fn run_until_converged<S, U, R>(
+    update: &mut U,
+    state: &mut S,
+    tolerance: f64,
+    max_steps: usize,
+    rng: &mut R,
+) -> Option<usize>
+where
+    S: SiteState<Site = f64>,
+    U: MonteCarloUpdate<S>,
+    R: Rng + ?Sized,
+{
+    let mut previous: Vec<f64> = state.sites().copied().collect();
+    for t in 1..=max_steps {
+        update.step(state, rng);
+        let converged = state
+            .sites()
+            .zip(&previous)
+            .all(|(x, y)| (x - y).abs() <= tolerance);
+        if converged {
+            return Some(t);
+        }
+        previous.clear();
+        previous.extend(state.sites().copied());
+    }
+    None
+}
+
+/// Group of sites sharing approximately the same opinion
+#[derive(Debug, Clone, Copy)]
+pub struct OpinionCluster {
+    /// Mean opinion of the cluster
+    pub opinion: f64,
+    /// Number of sites in the cluster
+    pub size: usize,
+}
+
+/// Opinion clusters
+/// Sorted opinions are split wherever consecutive values differ by more than `tolerance`,
+/// giving the clusters in increasing order of opinion.
+#[derive(Debug, Clone, Copy)]
+pub struct OpinionClusters {
+    /// Largest gap between consecutive opinions within a cluster,
+    /// the spread of a cluster being unbounded
+    pub tolerance: f64,
+}
+
+impl<S> StateMeasurement<S> for OpinionClusters
+where
+    S: SiteState<Site = f64>,
+{
+    type Type = Vec<OpinionCluster>;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let mut opinions: Vec<f64> = state.sites().copied().collect();
+        opinions.sort_by(f64::total_cmp);
+        opinions
+            .chunk_by(|a, b| b - a <= self.tolerance)
+            .map(|cluster| OpinionCluster {
+                opinion: cluster.iter().sum::<f64>() / cluster.len() as f64,
+                size: cluster.len(),
+            })
+            .collect()
+    }
+}
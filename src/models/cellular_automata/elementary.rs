@@ -0,0 +1,226 @@
+//! One-Dimensional Cellular Automata
+//!
+//! Synchronous automata on [`SquareLattice1D`] where the next color of each cell
+//! is given by a rule table over the colors of its radius $r$ neighborhood.
+
+use crate::{
+    models::StochasticProcess,
+    states::{InitialStateSpec, lattices::square_lattices::SquareLattice1D},
+};
+use rand::Rng;
+use rand_distr::{Distribution, WeightedIndex};
+
+/// Output of a rule table entry
+#[derive(Debug, Clone)]
+pub enum RuleOutput {
+    /// Deterministic color
+    Fixed(u8),
+    /// Color drawn with given weights
+    Random(WeightedIndex<f64>),
+}
+
+/// Rule table of a $k$-color radius $r$ automaton
+///
+/// Neighborhoods $(s_{i-r}, \ldots, s_{i+r})$ are indexed by their base $k$ value
+/// with the leftmost cell as the most significant digit, as in Wolfram's convention.
+#[derive(Debug, Clone)]
+pub struct RuleTable {
+    /// Neighborhood radius $r$
+    radius: usize,
+    /// Number of colors $k$
+    colors: u8,
+    /// Output for each neighborhood
+    outputs: Vec<RuleOutput>,
+}
+
+impl RuleTable {
+    /// Number of neighborhoods of a $k$-color radius $r$ automaton
+    #[inline(always)]
+    fn neighborhood_count(radius: usize, colors: u8) -> usize {
+        (colors as usize).pow(2 * radius as u32 + 1)
+    }
+
+    /// Create deterministic rule table from the output color of each neighborhood
+    ///
+    /// Panics if the number of outputs does not match the number of neighborhoods or a color is out of range.
+    pub fn new(radius: usize, colors: u8, outputs: Vec<u8>) -> Self {
+        assert_eq!(
+            outputs.len(),
+            Self::neighborhood_count(radius, colors),
+            "Rule table must have one output per neighborhood"
+        );
+        assert!(
+            outputs.iter().all(|&c| c < colors),
+            "Rule table outputs must be valid colors"
+        );
+        Self {
+            radius,
+            colors,
+            outputs: outputs.into_iter().map(RuleOutput::Fixed).collect(),
+        }
+    }
+
+    /// Create probabilistic rule table from the color weights of each neighborhood
+    ///
+    /// Panics if the number of entries does not match the number of neighborhoods
+    /// or the weights of an entry are not `colors` valid weights.
+    pub fn probabilistic(radius: usize, colors: u8, weights: Vec<Vec<f64>>) -> Self {
+        assert_eq!(
+            weights.len(),
+            Self::neighborhood_count(radius, colors),
+            "Rule table must have one entry per neighborhood"
+        );
+        let outputs = weights
+            .into_iter()
+            .map(|w| {
+                assert_eq!(
+                    w.len(),
+                    colors as usize,
+                    "Rule table entries must weight every color"
+                );
+                RuleOutput::Random(WeightedIndex::new(w).expect("Invalid rule table weights"))
+            })
+            .collect();
+        Self {
+            radius,
+            colors,
+            outputs,
+        }
+    }
+
+    /// Create rule table from its Wolfram rule number,
+    /// whose base $k$ digits are the outputs of each neighborhood
+    ///
+    /// Panics if the rule number does not fit the number of neighborhoods.
+    pub fn from_rule_number(rule: u128, radius: usize, colors: u8) -> Self {
+        let k = colors as u128;
+        let count = Self::neighborhood_count(radius, colors);
+        let mut rest = rule;
+        let outputs = (0..count)
+            .map(|_| {
+                let digit = (rest % k) as u8;
+                rest /= k;
+                digit
+            })
+            .collect();
+        assert_eq!(rest, 0, "Rule number out of range");
+        Self::new(radius, colors, outputs)
+    }
+
+    /// Elementary cellular automaton with a given Wolfram rule number
+    #[inline(always)]
+    pub fn wolfram(rule: u8) -> Self {
+        Self::from_rule_number(rule as u128, 1, 2)
+    }
+
+    /// Probabilistic elementary automaton with the probability of a live cell
+    /// for each neighborhood `0b000..=0b111`
+    pub fn probabilistic_elementary(probabilities: [f64; 8]) -> Self {
+        let weights = probabilities
+            .into_iter()
+            .map(|p| vec![1.0 - p, p])
+            .collect();
+        Self::probabilistic(1, 2, weights)
+    }
+
+    /// Neighborhood radius $r$
+    #[inline(always)]
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Number of colors $k$
+    #[inline(always)]
+    pub fn colors(&self) -> u8 {
+        self.colors
+    }
+
+    /// Output color for a given neighborhood index
+    #[inline(always)]
+    fn apply<R: Rng + ?Sized>(&self, neighborhood: usize, rng: &mut R) -> u8 {
+        match &self.outputs[neighborhood] {
+            RuleOutput::Fixed(c) => *c,
+            RuleOutput::Random(dist) => dist.sample(rng) as u8,
+        }
+    }
+}
+
+/// One-dimensional cellular automaton with synchronous updates
+#[derive(Debug, Clone)]
+pub struct CellularAutomaton1D<T>
+where
+    T: Clone + Copy + Into<u8> + From<u8>,
+{
+    /// Current state
+    state: SquareLattice1D<T>,
+    /// Next colors
+    buffer: Vec<u8>,
+    /// Rule table
+    rule: RuleTable,
+    /// Number of performed updates
+    time: usize,
+}
+
+impl<T> CellularAutomaton1D<T>
+where
+    T: Clone + Copy + Into<u8> + From<u8>,
+{
+    /// Create new automaton from an initial state and rule table
+    pub fn new(state: SquareLattice1D<T>, rule: RuleTable) -> Self {
+        Self {
+            buffer: vec![0; state.site_count()],
+            state,
+            rule,
+            time: 0,
+        }
+    }
+
+    /// Rule table
+    #[inline(always)]
+    pub fn rule(&self) -> &RuleTable {
+        &self.rule
+    }
+}
+
+impl<T> StochasticProcess for CellularAutomaton1D<T>
+where
+    T: Clone + Copy + Into<u8> + From<u8>,
+{
+    type State = SquareLattice1D<T>;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        self.buffer.resize(state.site_count(), 0);
+        self.state = state;
+    }
+
+    fn reset<I: InitialStateSpec<Self::State>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.time = 0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time as f64
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let length = self.state.length();
+        let (r, k) = (self.rule.radius, self.rule.colors as usize);
+        for (i, next) in self.buffer.iter_mut().enumerate() {
+            let neighborhood = (0..=2 * r).fold(0, |n, j| {
+                let cell = (i + j + length * r - r) % length;
+                n * k + self.state.state[cell].into() as usize
+            });
+            *next = self.rule.apply(neighborhood, rng);
+        }
+        for (s, &c) in self.state.sites_mut().zip(&self.buffer) {
+            *s = T::from(c);
+        }
+        self.time += 1;
+    }
+}
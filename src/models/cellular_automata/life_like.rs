@@ -0,0 +1,270 @@
+//! Life-Like Cellular Automata
+//!
+//! Outer-totalistic automata where the next state of each cell depends on its own state
+//! and the number of live cells in its Moore neighborhood:
+//! 8 cells on [`SquareLattice2D`] and 26 cells on [`SquareLattice3D`].
+//! The lattice side length must be at least 3,
+//! otherwise periodic images of the same cell would be counted more than once.
+
+use crate::{
+    models::StochasticProcess,
+    states::{
+        InitialStateSpec,
+        lattices::square_lattices::{SquareLattice, SquareLattice2D, SquareLattice3D},
+        sites::Occupancy,
+    },
+};
+use ndarray::{Axis, Dimension, parallel::prelude::*};
+use rand::Rng;
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Error parsing a birth/survival rule string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleParseError(String);
+
+impl Display for RuleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid birth/survival rule: {}", self.0)
+    }
+}
+
+impl Error for RuleParseError {}
+
+/// Birth/survival rule
+/// - Dead cells with a neighbor count in the birth set become alive
+/// - Live cells with a neighbor count in the survival set stay alive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LifeLikeRule {
+    /// Bit mask of the birth neighbor counts
+    birth: u32,
+    /// Bit mask of the survival neighbor counts
+    survival: u32,
+}
+
+impl LifeLikeRule {
+    /// Create rule from birth and survival neighbor counts
+    ///
+    /// Panics if a count exceeds 31.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let mask = |counts: &[usize]| {
+            counts.iter().fold(0u32, |m, &n| {
+                assert!(n < 32, "Neighbor counts must be below 32");
+                m | (1 << n)
+            })
+        };
+        Self {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+
+    /// Conway's Game of Life `B3/S23`
+    pub fn conway() -> Self {
+        Self::new(&[3], &[2, 3])
+    }
+
+    /// HighLife `B36/S23`
+    pub fn highlife() -> Self {
+        Self::new(&[3, 6], &[2, 3])
+    }
+
+    /// Seeds `B2/S`
+    pub fn seeds() -> Self {
+        Self::new(&[2], &[])
+    }
+
+    /// Day & Night `B3678/S34678`
+    pub fn day_and_night() -> Self {
+        Self::new(&[3, 6, 7, 8], &[3, 4, 6, 7, 8])
+    }
+
+    /// Next state of a cell with `n` live neighbors
+    #[inline(always)]
+    pub fn apply(&self, cell: Occupancy, n: usize) -> Occupancy {
+        let mask = match cell {
+            Occupancy::Empty => self.birth,
+            Occupancy::Occupied => self.survival,
+        };
+        Occupancy::from(mask & (1 << n) != 0)
+    }
+}
+
+/// Parse rule strings such as `B3/S23`
+///
+/// Counts are single digits unless separated by commas, as in `B5,6,7/S5,6,7` for 3D rules.
+impl FromStr for LifeLikeRule {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || RuleParseError(s.to_owned());
+        let parse_counts = |part: &str, prefix: char| -> Result<Vec<usize>, RuleParseError> {
+            let digits = part
+                .strip_prefix([prefix, prefix.to_ascii_lowercase()])
+                .ok_or_else(error)?;
+            let counts: Option<Vec<usize>> = if digits.contains(',') {
+                digits.split(',').map(|d| d.trim().parse().ok()).collect()
+            } else {
+                digits
+                    .chars()
+                    .map(|c| c.to_digit(10).map(|d| d as usize))
+                    .collect()
+            };
+            counts
+                .filter(|c| c.iter().all(|&n| n < 32))
+                .ok_or_else(error)
+        };
+        let (birth, survival) = s.trim().split_once('/').ok_or_else(error)?;
+        Ok(Self::new(
+            &parse_counts(birth, 'B')?,
+            &parse_counts(survival, 'S')?,
+        ))
+    }
+}
+
+/// Life-like automaton with double-buffered synchronous updates
+///
+/// Each update fills the back buffer in parallel over the rows of the lattice
+/// and then swaps it with the current state.
+#[derive(Debug, Clone)]
+pub struct LifeLike<S> {
+    /// Current state
+    state: S,
+    /// Back buffer
+    next: S,
+    /// Birth/survival rule
+    rule: LifeLikeRule,
+    /// Number of performed updates
+    time: usize,
+}
+
+/// Check that the Moore neighborhood of a cell does not wrap onto itself
+///
+/// Panics if the side length is less than 3.
+#[inline(always)]
+fn assert_moore_length<D: Dimension>(state: &SquareLattice<Occupancy, D>) {
+    assert!(
+        state.length() >= 3,
+        "Life-like automata require a side length of at least 3"
+    );
+}
+
+impl<D: Dimension> LifeLike<SquareLattice<Occupancy, D>> {
+    /// Create new automaton from an initial state and rule
+    ///
+    /// Panics if the side length of the lattice is less than 3.
+    pub fn new(state: SquareLattice<Occupancy, D>, rule: LifeLikeRule) -> Self {
+        assert_moore_length(&state);
+        Self {
+            next: state.clone(),
+            state,
+            rule,
+            time: 0,
+        }
+    }
+
+    /// Birth/survival rule
+    #[inline(always)]
+    pub fn rule(&self) -> LifeLikeRule {
+        self.rule
+    }
+}
+
+impl StochasticProcess for LifeLike<SquareLattice2D<Occupancy>> {
+    type State = SquareLattice2D<Occupancy>;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        assert_moore_length(&state);
+        self.next = state.clone();
+        self.state = state;
+    }
+
+    fn reset<I: InitialStateSpec<Self::State>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.time = 0;
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, _rng: &mut R) {
+        let (current, rule) = (&self.state, self.rule);
+        let period = &current.period;
+        self.next
+            .state
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, mut row)| {
+                let rows = [period.prev(i), i, period.next(i)];
+                for (j, cell) in row.iter_mut().enumerate() {
+                    let cols = [period.prev(j), j, period.next(j)];
+                    let n = rows
+                        .iter()
+                        .flat_map(|&ni| cols.iter().map(move |&nj| [ni, nj]))
+                        .filter(|&idx| idx != [i, j] && current.state[idx].is_occupied())
+                        .count();
+                    *cell = rule.apply(current.state[[i, j]], n);
+                }
+            });
+        std::mem::swap(&mut self.state, &mut self.next);
+        self.time += 1;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time as f64
+    }
+}
+
+impl StochasticProcess for LifeLike<SquareLattice3D<Occupancy>> {
+    type State = SquareLattice3D<Occupancy>;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        assert_moore_length(&state);
+        self.next = state.clone();
+        self.state = state;
+    }
+
+    fn reset<I: InitialStateSpec<Self::State>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.time = 0;
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, _rng: &mut R) {
+        let (current, rule) = (&self.state, self.rule);
+        let period = &current.period;
+        self.next
+            .state
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, mut plane)| {
+                let layers = [period.prev(i), i, period.next(i)];
+                for ((j, k), cell) in plane.indexed_iter_mut() {
+                    let rows = [period.prev(j), j, period.next(j)];
+                    let cols = [period.prev(k), k, period.next(k)];
+                    let n = layers
+                        .iter()
+                        .flat_map(|&ni| rows.iter().map(move |&nj| (ni, nj)))
+                        .flat_map(|(ni, nj)| cols.iter().map(move |&nk| [ni, nj, nk]))
+                        .filter(|&idx| idx != [i, j, k] && current.state[idx].is_occupied())
+                        .count();
+                    *cell = rule.apply(current.state[[i, j, k]], n);
+                }
+            });
+        std::mem::swap(&mut self.state, &mut self.next);
+        self.time += 1;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time as f64
+    }
+}
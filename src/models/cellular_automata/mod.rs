@@ -0,0 +1,8 @@
+//! Cellular Automata
+//!
+
+/// One-dimensional automata with neighborhood rule tables
+pub mod elementary;
+
+/// Life-like outer-totalistic automata
+pub mod life_like;
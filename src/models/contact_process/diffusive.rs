@@ -0,0 +1,204 @@
+//! Diffusive Contact Process
+//!
+
+use super::{ContactProcess, UpdateScheme};
+use crate::{
+    models::{AbsorbingProcess, StochasticProcess},
+    states::{InitialStateSpec, SimpleSwapDiffusion, SiteStateNN, sites::Occupancy},
+};
+use rand::{Rng, seq::IteratorRandom};
+use rand_distr::{Bernoulli, Binomial, Distribution};
+
+/// Coupling between reaction and diffusion
+#[derive(Debug, Clone, Copy)]
+pub enum DiffusionCoupling {
+    /// Each unit of time a reaction step is followed by a swap diffusion sweep
+    Interleaved {
+        /// Swap coin of the diffusion sweep
+        diffusion_coin: Bernoulli,
+    },
+    /// Reaction and hopping events of active sites share a single event clock
+    EventClock {
+        /// Hopping rate $D$ of each particle
+        diffusion_rate: f64,
+    },
+}
+
+/// Contact process with particle diffusion
+#[derive(Debug, Clone)]
+pub struct DiffusiveContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy> + SimpleSwapDiffusion,
+{
+    /// Underlying contact process
+    cp: ContactProcess<S>,
+    /// Coupling between reaction and diffusion
+    coupling: DiffusionCoupling,
+}
+
+impl<S> DiffusiveContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy> + SimpleSwapDiffusion,
+{
+    /// Create new process from an initial state, infection rate $\lambda$ and diffusion coupling
+    pub fn new(state: S, infection_rate: f64, coupling: DiffusionCoupling) -> Self {
+        Self {
+            cp: ContactProcess::new(state, infection_rate),
+            coupling,
+        }
+    }
+
+    /// Use a given site selection scheme for the interleaved reaction steps
+    pub fn with_scheme(mut self, scheme: UpdateScheme) -> Self {
+        self.cp.scheme = scheme;
+        self
+    }
+
+    /// Underlying contact process
+    #[inline(always)]
+    pub fn contact_process(&self) -> &ContactProcess<S> {
+        &self.cp
+    }
+
+    /// Swap a site with a random nearest neighbor with the coin probability,
+    /// moving the swapped particle in the active list
+    fn swap_with_neighbor<R: Rng + ?Sized>(
+        &mut self,
+        idx: S::Index,
+        diffusion_coin: Bernoulli,
+        rng: &mut R,
+    ) {
+        let cp = &mut self.cp;
+        let Some(nn_idx) = cp.state.nearest_neighbors_index(idx).choose(rng) else {
+            return;
+        };
+        let (a, b) = (cp.state[idx], cp.state[nn_idx]);
+        if a == b || !Distribution::<bool>::sample(&diffusion_coin, rng) {
+            return;
+        }
+        cp.state[idx] = b;
+        cp.state[nn_idx] = a;
+        let (k, nn_k) = (cp.state.linear_index(idx), cp.state.linear_index(nn_idx));
+        let (from, to) = if a.is_occupied() {
+            (k, nn_k)
+        } else {
+            (nn_k, k)
+        };
+        cp.active.remove(from);
+        cp.active.insert(to);
+    }
+
+    /// Swap diffusion sweep of $N$ visits as in [`SimpleSwapDiffusion::diffuse`]
+    ///
+    /// Only visits landing on a bond between a particle and an empty site change the state.
+    /// On sparse states the $|A|$ particles are visited directly instead, a visit landing
+    /// on a bond of a given particle with probability $2 |A| / N$ as all sites have the same
+    /// number of nearest neighbors, so that the cost scales with the number of particles.
+    fn diffusion_sweep<R: Rng + ?Sized>(&mut self, diffusion_coin: Bernoulli, rng: &mut R) {
+        let site_count = self.cp.state.site_count();
+        let particles = self.cp.active.len();
+        if 2 * particles < site_count {
+            // Swaps conserve the number of particles
+            let p = 2.0 * particles as f64 / site_count as f64;
+            let visits = Binomial::new(site_count as u64, p)
+                .expect("Visit probability must lie in [0, 1]")
+                .sample(rng);
+            for _ in 0..visits {
+                let k = self
+                    .cp
+                    .active
+                    .choose(rng)
+                    .expect("Particles must be conserved");
+                let idx = self.cp.state.site_index(k);
+                self.swap_with_neighbor(idx, diffusion_coin, rng);
+            }
+        } else {
+            for _ in 0..site_count {
+                let idx = self.cp.state.sample(rng);
+                self.swap_with_neighbor(idx, diffusion_coin, rng);
+            }
+        }
+    }
+
+    /// Perform a single event on the shared clock
+    fn event<R: Rng + ?Sized>(&mut self, diffusion_rate: f64, rng: &mut R) {
+        let cp = &mut self.cp;
+        let Some(k) = cp.active.choose(rng) else {
+            return;
+        };
+        let total_rate = 1.0 + cp.infection_rate + diffusion_rate;
+        cp.time += 1.0 / (total_rate * cp.active.len() as f64);
+        let u = rng.gen_range(0.0..total_rate);
+        if u < 1.0 + cp.infection_rate {
+            cp.update_site(k, rng);
+            return;
+        }
+        // Hop into an empty nearest neighbor
+        let idx = cp.state.site_index(k);
+        if let Some(nn_idx) = cp.state.nearest_neighbors_index(idx).choose(rng)
+            && !cp.state[nn_idx].is_occupied()
+        {
+            cp.state[idx] = Occupancy::Empty;
+            cp.state[nn_idx] = Occupancy::Occupied;
+            cp.active.remove(k);
+            cp.active.insert(cp.state.linear_index(nn_idx));
+        }
+    }
+}
+
+impl<S> StochasticProcess for DiffusiveContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy> + SimpleSwapDiffusion,
+{
+    type State = S;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        self.cp.state()
+    }
+
+    #[inline(always)]
+    fn set_state(&mut self, state: Self::State) {
+        self.cp.set_state(state);
+    }
+
+    #[inline(always)]
+    fn reset<I: InitialStateSpec<S>>(&mut self, spec: &mut I) {
+        self.cp.reset(spec);
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.cp.time()
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        match self.coupling {
+            DiffusionCoupling::Interleaved { diffusion_coin } => {
+                self.cp.step(rng);
+                self.diffusion_sweep(diffusion_coin, rng);
+            }
+            DiffusionCoupling::EventClock { diffusion_rate } => {
+                let end = self.cp.time + 1.0;
+                while self.cp.time < end && !self.cp.active.is_empty() {
+                    self.event(diffusion_rate, rng);
+                }
+            }
+        }
+    }
+}
+
+impl<S> AbsorbingProcess for DiffusiveContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy> + SimpleSwapDiffusion,
+{
+    #[inline(always)]
+    fn active_count(&self) -> usize {
+        self.cp.active_count()
+    }
+
+    #[inline(always)]
+    fn active_indices(&self) -> impl Iterator<Item = usize> {
+        self.cp.active_indices()
+    }
+}
@@ -0,0 +1,180 @@
+//! Contact Process
+//!
+
+use super::{AbsorbingProcess, StochasticProcess};
+use crate::states::{
+    InitialStateSpec, SiteState, SiteStateNN, StateMeasurement, active_sites::ActiveSites,
+    sites::Occupancy,
+};
+use rand::{Rng, seq::IteratorRandom};
+
+/// Site selection scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpdateScheme {
+    /// Random sequential updates over all sites
+    Sequential,
+    /// Updates over the list of active sites only
+    #[default]
+    ActiveSiteList,
+}
+
+/// Contact process
+/// - Occupied sites become empty with rate $1$
+/// - Occupied sites occupy a random nearest neighbor with rate $\lambda$
+///
+/// Each event advances time by $1 / ((1 + \lambda) N_s)$,
+/// where $N_s$ is the number of sites eligible for selection.
+#[derive(Debug, Clone)]
+pub struct ContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy>,
+{
+    /// Current state
+    state: S,
+    /// Infection rate $\lambda$
+    infection_rate: f64,
+    /// Site selection scheme
+    scheme: UpdateScheme,
+    /// Occupied sites
+    active: ActiveSites,
+    /// Elapsed time
+    time: f64,
+}
+
+impl<S> ContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy>,
+{
+    /// Create new process from an initial state and infection rate $\lambda$
+    pub fn new(state: S, infection_rate: f64) -> Self {
+        let active = ActiveSites::from_state(&state, Occupancy::is_occupied);
+        Self {
+            state,
+            infection_rate,
+            scheme: UpdateScheme::default(),
+            active,
+            time: 0.0,
+        }
+    }
+
+    /// Use a given site selection scheme
+    pub fn with_scheme(mut self, scheme: UpdateScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Infection rate $\lambda$
+    #[inline(always)]
+    pub fn infection_rate(&self) -> f64 {
+        self.infection_rate
+    }
+
+    /// Occupied sites
+    #[inline(always)]
+    pub fn active_sites(&self) -> &ActiveSites {
+        &self.active
+    }
+
+    /// Update a given occupied site
+    fn update_site<R: Rng + ?Sized>(&mut self, k: usize, rng: &mut R) {
+        let idx = self.state.site_index(k);
+        if rng.gen_range(0.0..1.0 + self.infection_rate) < 1.0 {
+            // Recovery
+            self.state[idx] = Occupancy::Empty;
+            self.active.remove(k);
+        } else if let Some(nn_idx) = self.state.nearest_neighbors_index(idx).choose(rng) {
+            // Infection
+            if !self.state[nn_idx].is_occupied() {
+                self.state[nn_idx] = Occupancy::Occupied;
+                self.active.insert(self.state.linear_index(nn_idx));
+            }
+        }
+    }
+
+    /// Perform a single event and advance the clock
+    fn event<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        match self.scheme {
+            UpdateScheme::Sequential => {
+                let idx = self.state.sample(rng);
+                self.time += 1.0 / ((1.0 + self.infection_rate) * self.state.site_count() as f64);
+                if self.state[idx].is_occupied() {
+                    self.update_site(self.state.linear_index(idx), rng);
+                }
+            }
+            UpdateScheme::ActiveSiteList => {
+                if let Some(k) = self.active.choose(rng) {
+                    self.time += 1.0 / ((1.0 + self.infection_rate) * self.active.len() as f64);
+                    self.update_site(k, rng);
+                }
+            }
+        }
+    }
+}
+
+impl<S> StochasticProcess for ContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy>,
+{
+    type State = S;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+        self.active.rebuild(&self.state, Occupancy::is_occupied);
+    }
+
+    fn reset<I: InitialStateSpec<S>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.active.rebuild(&self.state, Occupancy::is_occupied);
+        self.time = 0.0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let end = self.time + 1.0;
+        while self.time < end && !self.active.is_empty() {
+            self.event(rng);
+        }
+    }
+}
+
+impl<S> AbsorbingProcess for ContactProcess<S>
+where
+    S: SiteStateNN<Site = Occupancy>,
+{
+    #[inline(always)]
+    fn active_count(&self) -> usize {
+        self.active.len()
+    }
+
+    #[inline(always)]
+    fn active_indices(&self) -> impl Iterator<Item = usize> {
+        self.active.iter()
+    }
+}
+
+/// Density of occupied sites
+#[derive(Debug, Clone, Copy)]
+pub struct Density;
+
+impl<S> StateMeasurement<S> for Density
+where
+    S: SiteState<Site = Occupancy>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        state.sites().filter(|s| s.is_occupied()).count() as f64 / state.site_count() as f64
+    }
+}
+
+/// Diffusive contact process
+pub mod diffusive;
@@ -0,0 +1,305 @@
+//! Cyclic Dominance
+//!
+//! Spatial rock–paper–scissors game of May–Leonard type with $S \geq 3$ species,
+//! forming spiral waves when mobility is below a critical value.
+//!
+//! Reichenbach, Mobilia & Frey, Nature 448, 1046 (2007)
+
+use super::MonteCarloUpdate;
+use crate::states::{
+    SiteCharRepr, SiteState, SiteStateNN, StateMeasurement,
+    lattices::square_lattices::SquareLattice2D,
+};
+use rand::{Rng, seq::IteratorRandom};
+use rand_distr::Distribution;
+
+/// Cyclic dominance site, empty or holding an individual of one of the species `0..S`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Organism {
+    #[default]
+    Empty,
+    Species(u8),
+}
+
+impl SiteCharRepr for Organism {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            Organism::Empty => ' ',
+            Organism::Species(i) => char::from_digit(*i as u32, 36).unwrap_or('?'),
+        }
+    }
+}
+
+/// Cyclic dominance update
+/// Each event selects a random site and a random nearest neighbor interacting by:
+/// - Predation with rate $\sigma$: species $i$ replaces its prey $i + 1$ by an empty site,
+///   species $S - 1$ preying on species $0$
+/// - Reproduction with rate $\mu$: a species places an offspring on an empty neighbor
+/// - Exchange with rate $\epsilon$: swap contents, giving the mobility $M = 2 \epsilon / N$
+///
+/// A Monte Carlo step consists of $N$ events, one of which is chosen proportionally to its rate.
+#[derive(Debug, Clone, Copy)]
+pub struct CyclicDominance {
+    /// Number of species $S$
+    species: u8,
+    /// Predation rate $\sigma$
+    pub predation_rate: f64,
+    /// Reproduction rate $\mu$
+    pub reproduction_rate: f64,
+    /// Exchange rate $\epsilon$
+    pub exchange_rate: f64,
+}
+
+impl CyclicDominance {
+    /// Create update with a given number of species and rates $\sigma$, $\mu$ and $\epsilon$
+    ///
+    /// Panics if there are fewer than three species, a rate is negative or all rates vanish.
+    pub fn new(
+        species: u8,
+        predation_rate: f64,
+        reproduction_rate: f64,
+        exchange_rate: f64,
+    ) -> Self {
+        assert!(
+            species >= 3,
+            "Cyclic dominance requires at least three species"
+        );
+        assert!(
+            predation_rate >= 0.0 && reproduction_rate >= 0.0 && exchange_rate >= 0.0,
+            "Rates must be non-negative"
+        );
+        assert!(
+            predation_rate + reproduction_rate + exchange_rate > 0.0,
+            "At least one rate must be positive"
+        );
+        Self {
+            species,
+            predation_rate,
+            reproduction_rate,
+            exchange_rate,
+        }
+    }
+
+    /// Number of species $S$
+    #[inline(always)]
+    pub fn species(&self) -> u8 {
+        self.species
+    }
+
+    /// Prey of a given species
+    #[inline(always)]
+    pub fn prey(&self, species: u8) -> u8 {
+        (species + 1) % self.species
+    }
+
+    /// Check if the individual at `a` preys on the one at `b`
+    #[inline(always)]
+    fn preys_on(&self, a: Organism, b: Organism) -> bool {
+        match (a, b) {
+            (Organism::Species(i), Organism::Species(j)) => j == self.prey(i),
+            _ => false,
+        }
+    }
+
+    /// Perform a single event
+    fn event<S, R>(&self, state: &mut S, rng: &mut R)
+    where
+        S: SiteStateNN<Site = Organism>,
+        R: Rng + ?Sized,
+    {
+        let idx = state.sample(rng);
+        let Some(nn_idx) = state.nearest_neighbors_index(idx).choose(rng) else {
+            return;
+        };
+        let (a, b) = (state[idx], state[nn_idx]);
+        let total_rate = self.predation_rate + self.reproduction_rate + self.exchange_rate;
+        let u = rng.gen_range(0.0..total_rate);
+        if u < self.predation_rate {
+            // Predation
+            if self.preys_on(a, b) {
+                state[nn_idx] = Organism::Empty;
+            } else if self.preys_on(b, a) {
+                state[idx] = Organism::Empty;
+            }
+        } else if u < self.predation_rate + self.reproduction_rate {
+            // Reproduction
+            if a == Organism::Empty {
+                state[idx] = b;
+            } else if b == Organism::Empty {
+                state[nn_idx] = a;
+            }
+        } else {
+            // Exchange
+            state[idx] = b;
+            state[nn_idx] = a;
+        }
+    }
+}
+
+impl<S> MonteCarloUpdate<S> for CyclicDominance
+where
+    S: SiteStateNN<Site = Organism>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            self.event(state, rng);
+        }
+    }
+}
+
+/// Uniformly random site, empty or holding one of the $S$ species
+impl Distribution<Organism> for CyclicDominance {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Organism {
+        match rng.gen_range(0..=self.species) {
+            0 => Organism::Empty,
+            i => Organism::Species(i - 1),
+        }
+    }
+}
+
+/// Density of each species `0..S` indexed by species
+/// Individuals of species outside `0..S` are not counted.
+#[derive(Debug, Clone, Copy)]
+pub struct SpeciesDensities {
+    /// Number of species $S$
+    pub species: u8,
+}
+
+impl<S> StateMeasurement<S> for SpeciesDensities
+where
+    S: SiteState<Site = Organism>,
+{
+    type Type = Vec<f64>;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let mut counts = vec![0usize; self.species as usize];
+        for s in state.sites() {
+            if let Organism::Species(i) = s
+                && let Some(count) = counts.get_mut(*i as usize)
+            {
+                *count += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .map(|n| n as f64 / state.site_count() as f64)
+            .collect()
+    }
+}
+
+/// Number of species among `0..S` present in the state
+#[derive(Debug, Clone, Copy)]
+pub struct SurvivingSpecies {
+    /// Number of species $S$
+    pub species: u8,
+}
+
+impl<S> StateMeasurement<S> for SurvivingSpecies
+where
+    S: SiteState<Site = Organism>,
+{
+    type Type = usize;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        SpeciesDensities {
+            species: self.species,
+        }
+        .measure(state)
+        .iter()
+        .filter(|&&rho| rho > 0.0)
+        .count()
+    }
+}
+
+/// Run an update until a species goes extinct or for at most `max_steps` Monte Carlo steps
+/// Returns the extinction time in Monte Carlo steps, if reached
+pub fn run_until_extinction<S, R>(
+    update: &mut CyclicDominance,
+    state: &mut S,
+    max_steps: usize,
+    rng: &mut R,
+) -> Option<usize>
+where
+    S: SiteStateNN<Site = Organism>,
+    R: Rng + ?Sized,
+{
+    let surviving = SurvivingSpecies {
+        species: update.species(),
+    };
+    let species = update.species() as usize;
+    for t in 0..max_steps {
+        if surviving.measure(state) < species {
+            return Some(t);
+        }
+        update.step(state, rng);
+    }
+    (surviving.measure(state) < species).then_some(max_steps)
+}
+
+/// Spatial correlation function of a species
+/// $g(r) = \langle s(x) s(x + r) \rangle - \langle s \rangle^2$ for $r = 0, \ldots$, `max_distance`,
+/// where $s$ indicates the species, averaged over both lattice axes
+#[derive(Debug, Clone, Copy)]
+pub struct SpatialCorrelation {
+    /// Species
+    pub species: u8,
+    /// Largest distance
+    pub max_distance: usize,
+}
+
+impl StateMeasurement<SquareLattice2D<Organism>> for SpatialCorrelation {
+    type Type = Vec<f64>;
+
+    fn measure(&self, state: &SquareLattice2D<Organism>) -> Self::Type {
+        let length = state.length();
+        let species = Organism::Species(self.species);
+        let is_species = |i: usize, j: usize| (state.state[[i, j]] == species) as usize;
+        let density =
+            state.sites().filter(|&&s| s == species).count() as f64 / state.site_count() as f64;
+        (0..=self.max_distance)
+            .map(|r| {
+                let mut pairs = 0;
+                for i in 0..length {
+                    for j in 0..length {
+                        if is_species(i, j) == 1 {
+                            pairs +=
+                                is_species((i + r) % length, j) + is_species(i, (j + r) % length);
+                        }
+                    }
+                }
+                pairs as f64 / (2 * state.site_count()) as f64 - density * density
+            })
+            .collect()
+    }
+}
+
+/// Correlation length $\xi$ of a species at which the normalized correlation $g(r) / g(0)$ decays to $1 / e$
+///
+/// Interpolated linearly between distances, `None` if the species is absent
+/// or its correlation does not decay within half the lattice length.
+#[derive(Debug, Clone, Copy)]
+pub struct CorrelationLength {
+    /// Species
+    pub species: u8,
+}
+
+impl StateMeasurement<SquareLattice2D<Organism>> for CorrelationLength {
+    type Type = Option<f64>;
+
+    fn measure(&self, state: &SquareLattice2D<Organism>) -> Self::Type {
+        let correlation = SpatialCorrelation {
+            species: self.species,
+            max_distance: state.length() / 2,
+        }
+        .measure(state);
+        if correlation[0] <= 0.0 {
+            return None;
+        }
+        let threshold = correlation[0] / std::f64::consts::E;
+        correlation.windows(2).enumerate().find_map(|(r, g)| {
+            (g[1] < threshold).then(|| r as f64 + (g[0] - threshold) / (g[0] - g[1]))
+        })
+    }
+}
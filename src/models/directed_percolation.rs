@@ -0,0 +1,196 @@
+//! Directed Percolation
+//!
+//! Synchronous probabilistic automata where the state of each site at time $t + 1$
+//! depends on the number $n$ of active nearest neighbors (parents) at time $t$.
+
+use super::{AbsorbingProcess, PARALLEL_CHUNK_SIZE, StochasticProcess, chunk_seeds};
+use crate::states::{InitialStateSpec, SiteStateNN, sites::Occupancy};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rayon::prelude::*;
+
+/// Activation rule as a function of the number of active parents
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DirectedPercolationRule {
+    /// Each active parent independently activates the site with probability $p$
+    Bond(f64),
+    /// Site is activated with probability $p$ if at least one parent is active
+    Site(f64),
+    /// Domany–Kinzel automaton with probability $p_1$ for one active parent and $p_2$ for two
+    /// With more than two parents $p_2$ applies whenever at least two of them are active
+    DomanyKinzel(f64, f64),
+}
+
+impl DirectedPercolationRule {
+    /// Bond directed percolation with probability $p$
+    ///
+    /// Panics if $p$ lies outside $[0, 1]$.
+    pub fn bond(p: f64) -> Self {
+        Self::Bond(p).validated()
+    }
+
+    /// Site directed percolation with probability $p$
+    ///
+    /// Panics if $p$ lies outside $[0, 1]$.
+    pub fn site(p: f64) -> Self {
+        Self::Site(p).validated()
+    }
+
+    /// Domany–Kinzel automaton with probabilities $p_1$ and $p_2$
+    ///
+    /// Panics if a probability lies outside $[0, 1]$.
+    pub fn domany_kinzel(p1: f64, p2: f64) -> Self {
+        Self::DomanyKinzel(p1, p2).validated()
+    }
+
+    /// Check that all probabilities of the rule lie in $[0, 1]$
+    fn validated(self) -> Self {
+        let (p1, p2) = match self {
+            DirectedPercolationRule::Bond(p) | DirectedPercolationRule::Site(p) => (p, p),
+            DirectedPercolationRule::DomanyKinzel(p1, p2) => (p1, p2),
+        };
+        assert!(
+            (0.0..=1.0).contains(&p1) && (0.0..=1.0).contains(&p2),
+            "Activation probabilities must lie in [0, 1]"
+        );
+        self
+    }
+
+    /// Probability of activating a site with `n` active parents
+    #[inline(always)]
+    pub fn activation_probability(&self, n: usize) -> f64 {
+        match (*self, n) {
+            (_, 0) => 0.0,
+            (DirectedPercolationRule::Bond(p), n) => 1.0 - (1.0 - p).powi(n as i32),
+            (DirectedPercolationRule::Site(p), _) => p,
+            (DirectedPercolationRule::DomanyKinzel(p1, _), 1) => p1,
+            (DirectedPercolationRule::DomanyKinzel(_, p2), _) => p2,
+        }
+    }
+}
+
+/// Directed percolation process with synchronous parallel updates
+///
+/// The next state is built in a back buffer in parallel, each chunk of sites
+/// with its own generator seeded from the process generator, and then copied back.
+#[derive(Debug, Clone)]
+pub struct DirectedPercolation<S>
+where
+    S: SiteStateNN<Site = Occupancy> + Sync,
+{
+    /// Current state
+    state: S,
+    /// Next state in linear order
+    buffer: Vec<Occupancy>,
+    /// Activation rule
+    rule: DirectedPercolationRule,
+    /// Number of active sites
+    active_count: usize,
+    /// Number of performed updates
+    time: usize,
+}
+
+impl<S> DirectedPercolation<S>
+where
+    S: SiteStateNN<Site = Occupancy> + Sync,
+{
+    /// Create new process from an initial state and activation rule
+    ///
+    /// Panics if a probability of the rule lies outside $[0, 1]$.
+    pub fn new(state: S, rule: DirectedPercolationRule) -> Self {
+        let rule = rule.validated();
+        let active_count = state.sites().filter(|s| s.is_occupied()).count();
+        Self {
+            buffer: vec![Occupancy::Empty; state.site_count()],
+            state,
+            rule,
+            active_count,
+            time: 0,
+        }
+    }
+
+    /// Activation rule
+    #[inline(always)]
+    pub fn rule(&self) -> DirectedPercolationRule {
+        self.rule
+    }
+}
+
+impl<S> StochasticProcess for DirectedPercolation<S>
+where
+    S: SiteStateNN<Site = Occupancy> + Sync,
+{
+    type State = S;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+        self.buffer
+            .resize(self.state.site_count(), Occupancy::Empty);
+        self.active_count = self.state.sites().filter(|s| s.is_occupied()).count();
+    }
+
+    fn reset<I: InitialStateSpec<S>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.active_count = self.state.sites().filter(|s| s.is_occupied()).count();
+        self.time = 0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time as f64
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        if self.active_count == 0 {
+            return;
+        }
+        let seeds = chunk_seeds(self.buffer.len(), rng);
+        let (state, rule) = (&self.state, self.rule);
+        self.active_count = self
+            .buffer
+            .par_chunks_mut(PARALLEL_CHUNK_SIZE)
+            .zip(seeds.par_iter())
+            .enumerate()
+            .map(|(c, (chunk, &seed))| {
+                let mut chunk_rng = StdRng::seed_from_u64(seed);
+                let mut active = 0;
+                for (offset, site) in chunk.iter_mut().enumerate() {
+                    let idx = state.site_index(c * PARALLEL_CHUNK_SIZE + offset);
+                    let n = state
+                        .nearest_neighbors(idx)
+                        .filter(|s| s.is_occupied())
+                        .count();
+                    *site = Occupancy::from(chunk_rng.gen_bool(rule.activation_probability(n)));
+                    active += site.is_occupied() as usize;
+                }
+                active
+            })
+            .sum();
+        for (s, &x) in self.state.sites_mut().zip(&self.buffer) {
+            *s = x;
+        }
+        self.time += 1;
+    }
+}
+
+impl<S> AbsorbingProcess for DirectedPercolation<S>
+where
+    S: SiteStateNN<Site = Occupancy> + Sync,
+{
+    #[inline(always)]
+    fn active_count(&self) -> usize {
+        self.active_count
+    }
+
+    #[inline(always)]
+    fn active_indices(&self) -> impl Iterator<Item = usize> {
+        self.state
+            .sites()
+            .enumerate()
+            .filter_map(|(k, s)| s.is_occupied().then_some(k))
+    }
+}
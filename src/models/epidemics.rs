@@ -0,0 +1,449 @@
+//! Compartmental Epidemic Models
+//!
+
+use super::{AbsorbingProcess, StochasticProcess};
+use crate::states::{
+    InitialStateSpec, SiteCharRepr, SiteState, SiteStateNN, StateMeasurement,
+    active_sites::ActiveSites,
+};
+use rand::{Rng, seq::IteratorRandom};
+use rand_distr::{Distribution, Exp1};
+
+/// Epidemic compartment of a site
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compartment {
+    #[default]
+    Susceptible,
+    Exposed,
+    Infected,
+    Recovered,
+}
+
+impl SiteCharRepr for Compartment {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            Compartment::Susceptible => ' ',
+            Compartment::Exposed => '░',
+            Compartment::Infected => '█',
+            Compartment::Recovered => '·',
+        }
+    }
+}
+
+/// Compartmental structure of the epidemic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpidemicKind {
+    /// Infected sites become susceptible again upon recovery
+    Sis,
+    /// Infected sites become permanently recovered
+    Sir,
+    /// Infection goes through a latent exposed stage before recovery
+    Seir,
+}
+
+/// Rates of the epidemic transitions
+#[derive(Debug, Clone, Copy)]
+pub struct EpidemicRates {
+    /// Transmission rate $\beta$ along each edge from an infected to a susceptible site
+    pub transmission: f64,
+    /// Recovery rate $\gamma$ of infected sites
+    pub recovery: f64,
+    /// Rate $\sigma$ at which exposed sites become infected
+    pub latency: f64,
+}
+
+/// Time evolution scheme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeScheme {
+    /// Synchronous updates with time step $\Delta t$,
+    /// each transition with rate $r$ happening with probability $1 - e^{-r \Delta t}$
+    Discrete {
+        /// Time step $\Delta t$
+        dt: f64,
+    },
+    /// Exact continuous-time (Gillespie) dynamics
+    Continuous,
+}
+
+/// Epidemic process
+/// Only infected and exposed sites are visited, so the cost scales with the outbreak.
+#[derive(Debug, Clone)]
+pub struct Epidemic<S>
+where
+    S: SiteStateNN<Site = Compartment>,
+{
+    /// Current state
+    state: S,
+    /// Compartmental structure
+    kind: EpidemicKind,
+    /// Transition rates
+    rates: EpidemicRates,
+    /// Time evolution scheme
+    scheme: TimeScheme,
+    /// Infected sites
+    infected: ActiveSites,
+    /// Exposed sites
+    exposed: ActiveSites,
+    /// Sum of the degrees of the infected sites
+    infected_degree: usize,
+    /// Largest degree in the state
+    max_degree: usize,
+    /// Infections since the last reset, including the initially infected and exposed sites
+    /// and reinfections in the SIS model
+    infections: usize,
+    /// Whether each site was infected or exposed since the last reset
+    ever_infected: Vec<bool>,
+    /// Number of distinct sites infected or exposed since the last reset
+    distinct_infections: usize,
+    /// Elapsed time
+    time: f64,
+}
+
+impl<S> Epidemic<S>
+where
+    S: SiteStateNN<Site = Compartment>,
+{
+    /// Create new epidemic from an initial state
+    pub fn new(state: S, kind: EpidemicKind, rates: EpidemicRates, scheme: TimeScheme) -> Self {
+        let mut epidemic = Self {
+            infected: ActiveSites::new(state.site_count()),
+            exposed: ActiveSites::new(state.site_count()),
+            state,
+            kind,
+            rates,
+            scheme,
+            infected_degree: 0,
+            max_degree: 0,
+            infections: 0,
+            ever_infected: Vec::new(),
+            distinct_infections: 0,
+            time: 0.0,
+        };
+        epidemic.rebuild();
+        epidemic.infections = epidemic.active_count();
+        epidemic
+    }
+
+    /// Compartmental structure
+    #[inline(always)]
+    pub fn kind(&self) -> EpidemicKind {
+        self.kind
+    }
+
+    /// Transition rates
+    #[inline(always)]
+    pub fn rates(&self) -> EpidemicRates {
+        self.rates
+    }
+
+    /// Number of infections since the last reset, including the initially infected and exposed sites
+    /// and reinfections in the SIS model
+    #[inline(always)]
+    pub fn infections(&self) -> usize {
+        self.infections
+    }
+
+    /// Number of distinct sites infected or exposed since the last reset,
+    /// including the initially infected and exposed sites
+    #[inline(always)]
+    pub fn distinct_infections(&self) -> usize {
+        self.distinct_infections
+    }
+
+    /// Run until the outbreak ends or `max_time` is reached and summarize it
+    pub fn run_outbreak<R: Rng + ?Sized>(&mut self, max_time: f64, rng: &mut R) -> Outbreak {
+        let ended = self.run_until_absorbed(max_time, rng);
+        Outbreak {
+            final_size: self.distinct_infections as f64 / self.state.site_count() as f64,
+            duration: self.time,
+            ended,
+        }
+    }
+
+    /// Rebuild infected and exposed lists and degrees from the current state,
+    /// marking its infected and exposed sites as ever infected
+    fn rebuild(&mut self) {
+        self.infected
+            .rebuild(&self.state, |s| *s == Compartment::Infected);
+        self.exposed
+            .rebuild(&self.state, |s| *s == Compartment::Exposed);
+        self.infected_degree = self.infected.iter().map(|k| self.degree(k)).sum();
+        self.max_degree = (0..self.state.site_count())
+            .map(|k| self.degree(k))
+            .max()
+            .unwrap_or(0);
+        self.ever_infected.resize(self.state.site_count(), false);
+        let active: Vec<usize> = self.active_indices().collect();
+        for k in active {
+            self.mark_infected(k);
+        }
+    }
+
+    /// Record that a site was infected or exposed
+    #[inline(always)]
+    fn mark_infected(&mut self, k: usize) {
+        if !self.ever_infected[k] {
+            self.ever_infected[k] = true;
+            self.distinct_infections += 1;
+        }
+    }
+
+    /// Degree of a site
+    #[inline(always)]
+    fn degree(&self, k: usize) -> usize {
+        self.state
+            .nearest_neighbors_index(self.state.site_index(k))
+            .count()
+    }
+
+    /// Set the compartment of a site keeping the bookkeeping consistent
+    fn set_compartment(&mut self, k: usize, compartment: Compartment) {
+        let idx = self.state.site_index(k);
+        match self.state[idx] {
+            Compartment::Infected => {
+                self.infected.remove(k);
+                self.infected_degree -= self.degree(k);
+            }
+            Compartment::Exposed => {
+                self.exposed.remove(k);
+            }
+            _ => {}
+        }
+        match compartment {
+            Compartment::Infected => {
+                self.infected.insert(k);
+                self.infected_degree += self.degree(k);
+            }
+            Compartment::Exposed => {
+                self.exposed.insert(k);
+            }
+            _ => {}
+        }
+        self.state[idx] = compartment;
+    }
+
+    /// Compartment of a newly infected susceptible site
+    #[inline(always)]
+    fn infection_target(&self) -> Compartment {
+        match self.kind {
+            EpidemicKind::Seir => Compartment::Exposed,
+            _ => Compartment::Infected,
+        }
+    }
+
+    /// Compartment of a recovered infected site
+    #[inline(always)]
+    fn recovery_target(&self) -> Compartment {
+        match self.kind {
+            EpidemicKind::Sis => Compartment::Susceptible,
+            _ => Compartment::Recovered,
+        }
+    }
+
+    /// Infect a site if it is susceptible
+    #[inline(always)]
+    fn infect(&mut self, k: usize) {
+        if self.state[self.state.site_index(k)] == Compartment::Susceptible {
+            self.set_compartment(k, self.infection_target());
+            self.infections += 1;
+            self.mark_infected(k);
+        }
+    }
+
+    /// Synchronous discrete time step
+    fn discrete_step<R: Rng + ?Sized>(&mut self, dt: f64, rng: &mut R) {
+        let probability = |rate: f64| 1.0 - f64::exp(-rate * dt);
+        let (p_transmission, p_recovery, p_latency) = (
+            probability(self.rates.transmission),
+            probability(self.rates.recovery),
+            probability(self.rates.latency),
+        );
+        // Decide all transitions on the current state before applying them
+        let mut newly_infected = Vec::new();
+        let mut recovered = Vec::new();
+        for k in self.infected.iter() {
+            let idx = self.state.site_index(k);
+            for nn_idx in self.state.nearest_neighbors_index(idx) {
+                if self.state[nn_idx] == Compartment::Susceptible && rng.gen_bool(p_transmission) {
+                    newly_infected.push(self.state.linear_index(nn_idx));
+                }
+            }
+            if rng.gen_bool(p_recovery) {
+                recovered.push(k);
+            }
+        }
+        let activated: Vec<usize> = self
+            .exposed
+            .iter()
+            .filter(|_| rng.gen_bool(p_latency))
+            .collect();
+        for k in recovered {
+            self.set_compartment(k, self.recovery_target());
+        }
+        for k in activated {
+            self.set_compartment(k, Compartment::Infected);
+        }
+        for k in newly_infected {
+            self.infect(k);
+        }
+        self.time += dt;
+    }
+
+    /// Single continuous time event
+    ///
+    /// Infection attempts pick an infected site proportionally to its degree by rejection
+    /// and a uniformly random neighbor, so attempts on non-susceptible neighbors are phantom events.
+    fn continuous_event<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let recovery = self.rates.recovery * self.infected.len() as f64;
+        let latency = self.rates.latency * self.exposed.len() as f64;
+        let transmission = self.rates.transmission * self.infected_degree as f64;
+        let total = recovery + latency + transmission;
+        if total <= 0.0 {
+            return;
+        }
+        let dt: f64 = Exp1.sample(rng);
+        self.time += dt / total;
+        let u = rng.gen_range(0.0..total);
+        if u < recovery {
+            if let Some(k) = self.infected.choose(rng) {
+                self.set_compartment(k, self.recovery_target());
+            }
+        } else if u < recovery + latency {
+            if let Some(k) = self.exposed.choose(rng) {
+                self.set_compartment(k, Compartment::Infected);
+            }
+        } else {
+            // Without edges there is nothing to transmit along
+            if self.max_degree == 0 {
+                return;
+            }
+            let k = loop {
+                let k = self.infected.choose(rng).unwrap();
+                if rng.gen_range(0..self.max_degree) < self.degree(k) {
+                    break k;
+                }
+            };
+            let idx = self.state.site_index(k);
+            if let Some(nn_idx) = self.state.nearest_neighbors_index(idx).choose(rng) {
+                self.infect(self.state.linear_index(nn_idx));
+            }
+        }
+    }
+}
+
+impl<S> StochasticProcess for Epidemic<S>
+where
+    S: SiteStateNN<Site = Compartment>,
+{
+    type State = S;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+        self.rebuild();
+    }
+
+    fn reset<I: InitialStateSpec<S>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.ever_infected.clear();
+        self.distinct_infections = 0;
+        self.rebuild();
+        self.infections = self.active_count();
+        self.time = 0.0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let end = self.time + 1.0;
+        while self.time < end && !self.is_absorbed() {
+            match self.scheme {
+                TimeScheme::Discrete { dt } => self.discrete_step(dt, rng),
+                TimeScheme::Continuous => self.continuous_event(rng),
+            }
+        }
+    }
+}
+
+impl<S> AbsorbingProcess for Epidemic<S>
+where
+    S: SiteStateNN<Site = Compartment>,
+{
+    #[inline(always)]
+    fn active_count(&self) -> usize {
+        self.infected.len() + self.exposed.len()
+    }
+
+    #[inline(always)]
+    fn active_indices(&self) -> impl Iterator<Item = usize> {
+        self.infected.iter().chain(self.exposed.iter())
+    }
+}
+
+/// Number of sites in each compartment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompartmentCounts {
+    pub susceptible: usize,
+    pub exposed: usize,
+    pub infected: usize,
+    pub recovered: usize,
+}
+
+impl<S> StateMeasurement<S> for CompartmentCounts
+where
+    S: SiteState<Site = Compartment>,
+{
+    type Type = CompartmentCounts;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        state
+            .sites()
+            .fold(CompartmentCounts::default(), |mut counts, s| {
+                match s {
+                    Compartment::Susceptible => counts.susceptible += 1,
+                    Compartment::Exposed => counts.exposed += 1,
+                    Compartment::Infected => counts.infected += 1,
+                    Compartment::Recovered => counts.recovered += 1,
+                }
+                counts
+            })
+    }
+}
+
+/// Prevalence: fraction of infected sites
+#[derive(Debug, Clone, Copy)]
+pub struct Prevalence;
+
+impl<S> StateMeasurement<S> for Prevalence
+where
+    S: SiteState<Site = Compartment>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let infected = state
+            .sites()
+            .filter(|&&s| s == Compartment::Infected)
+            .count();
+        infected as f64 / state.site_count() as f64
+    }
+}
+
+/// Summary of a single outbreak
+#[derive(Debug, Clone, Copy)]
+pub struct Outbreak {
+    /// Fraction of distinct sites ever infected or exposed
+    pub final_size: f64,
+    /// Time until no infected or exposed sites remain
+    pub duration: f64,
+    /// Whether the outbreak ended before the maximum time
+    pub ended: bool,
+}
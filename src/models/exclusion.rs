@@ -0,0 +1,341 @@
+//! Exclusion Processes
+//!
+//! Particles hopping on lattices with at most one particle per site.
+
+use super::StochasticProcess;
+use crate::states::{
+    InitialStateSpec, SiteState, SiteStateNN,
+    lattices::square_lattices::{SquareLattice1D, SquareLattice2D},
+    sites::Occupancy,
+};
+use rand::Rng;
+use rand_distr::Distribution;
+
+/// Boundary conditions of a one-dimensional exclusion process
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExclusionBoundary {
+    /// Particles leaving one end enter the other
+    Periodic,
+    /// Open chain with particle reservoirs at both ends
+    Open {
+        /// Injection rate $\alpha$ into the first site
+        injection: f64,
+        /// Extraction rate $\beta$ from the last site
+        extraction: f64,
+    },
+}
+
+/// Asymmetric simple exclusion process (ASEP) on a chain
+/// - Particles hop to the empty right neighbor with rate $p$ and to the empty left neighbor with rate $q$
+/// - With $q = 0$ this is the totally asymmetric process (TASEP)
+///
+/// Random sequential updates choose a bond uniformly and attempt each of its moves with probability proportional to its rate.
+#[derive(Debug, Clone)]
+pub struct ExclusionProcess {
+    /// Current state
+    state: SquareLattice1D<Occupancy>,
+    /// Right hop rate $p$
+    right_rate: f64,
+    /// Left hop rate $q$
+    left_rate: f64,
+    /// Boundary conditions
+    boundary: ExclusionBoundary,
+    /// Largest total rate of a bond
+    max_rate: f64,
+    /// Net number of particles moved to the right across all bonds
+    net_hops: i64,
+    /// Elapsed time
+    time: f64,
+}
+
+impl ExclusionProcess {
+    /// Create new process from an initial state, hop rates and boundary conditions
+    ///
+    /// Panics if a rate is negative or all rates vanish.
+    pub fn new(
+        state: SquareLattice1D<Occupancy>,
+        right_rate: f64,
+        left_rate: f64,
+        boundary: ExclusionBoundary,
+    ) -> Self {
+        let max_rate = match boundary {
+            ExclusionBoundary::Periodic => right_rate + left_rate,
+            ExclusionBoundary::Open {
+                injection,
+                extraction,
+            } => {
+                assert!(
+                    injection >= 0.0 && extraction >= 0.0,
+                    "Reservoir rates must be non-negative"
+                );
+                (right_rate + left_rate).max(injection).max(extraction)
+            }
+        };
+        assert!(
+            right_rate >= 0.0 && left_rate >= 0.0,
+            "Hop rates must be non-negative"
+        );
+        assert!(max_rate > 0.0, "At least one rate must be positive");
+        Self {
+            state,
+            right_rate,
+            left_rate,
+            boundary,
+            max_rate,
+            net_hops: 0,
+            time: 0.0,
+        }
+    }
+
+    /// Totally asymmetric process with unit right hop rate
+    pub fn tasep(state: SquareLattice1D<Occupancy>, boundary: ExclusionBoundary) -> Self {
+        Self::new(state, 1.0, 0.0, boundary)
+    }
+
+    /// Boundary conditions
+    #[inline(always)]
+    pub fn boundary(&self) -> ExclusionBoundary {
+        self.boundary
+    }
+
+    /// Number of bonds including the reservoir bonds of open chains
+    #[inline(always)]
+    fn bond_count(&self) -> usize {
+        match self.boundary {
+            ExclusionBoundary::Periodic => self.state.site_count(),
+            ExclusionBoundary::Open { .. } => self.state.site_count() + 1,
+        }
+    }
+
+    /// Mean particle current per bond since the last reset, zero before any time elapsed
+    pub fn current(&self) -> f64 {
+        if self.time <= 0.0 {
+            return 0.0;
+        }
+        self.net_hops as f64 / (self.time * self.bond_count() as f64)
+    }
+
+    /// Attempt a hop across the bond between sites `i` and `j = i + 1` (periodically)
+    #[inline(always)]
+    fn hop_attempt(&mut self, i: usize, j: usize, u: f64) {
+        let (a, b) = (self.state[i], self.state[j]);
+        if u < self.right_rate {
+            if a.is_occupied() && !b.is_occupied() {
+                self.state.state.swap(i, j);
+                self.net_hops += 1;
+            }
+        } else if u < self.right_rate + self.left_rate && !a.is_occupied() && b.is_occupied() {
+            self.state.state.swap(i, j);
+            self.net_hops -= 1;
+        }
+    }
+
+    /// Single random sequential update attempt
+    fn event<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let length = self.state.site_count();
+        let bond = rng.gen_range(0..self.bond_count());
+        let u = rng.gen_range(0.0..self.max_rate);
+        match self.boundary {
+            ExclusionBoundary::Periodic => {
+                self.hop_attempt(bond, self.state.period.next(bond), u);
+            }
+            ExclusionBoundary::Open {
+                injection,
+                extraction,
+            } => match bond {
+                // Reservoir to first site
+                0 => {
+                    if u < injection && !self.state[0].is_occupied() {
+                        self.state[0] = Occupancy::Occupied;
+                        self.net_hops += 1;
+                    }
+                }
+                // Last site to reservoir
+                b if b == length => {
+                    if u < extraction && self.state[length - 1].is_occupied() {
+                        self.state[length - 1] = Occupancy::Empty;
+                        self.net_hops += 1;
+                    }
+                }
+                b => self.hop_attempt(b - 1, b, u),
+            },
+        }
+        self.time += 1.0 / (self.bond_count() as f64 * self.max_rate);
+    }
+}
+
+impl StochasticProcess for ExclusionProcess {
+    type State = SquareLattice1D<Occupancy>;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    #[inline(always)]
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+    }
+
+    fn reset<I: InitialStateSpec<Self::State>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.net_hops = 0;
+        self.time = 0.0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let end = self.time + 1.0;
+        while self.time < end {
+            self.event(rng);
+        }
+    }
+}
+
+/// Katz–Lebowitz–Spohn driven lattice gas
+/// Lattice gas with Hamiltonian $H = -J \sum_{\langle i,j \rangle} n_i n_j$
+/// evolving through particle–hole exchanges between nearest neighbors with Metropolis rate
+/// $\min(1, e^{-\beta (\Delta H - E \delta)})$, where $\delta = \pm 1$ for hops along and against
+/// the driving field, which points along the second axis, and $\delta = 0$ otherwise.
+#[derive(Debug, Clone)]
+pub struct DrivenLatticeGas {
+    /// Current state
+    state: SquareLattice2D<Occupancy>,
+    /// Coupling constant $J$
+    coupling: f64,
+    /// Inverse temperature $\beta$
+    beta: f64,
+    /// Driving field $E$
+    field: f64,
+    /// Net number of hops along the field
+    net_hops: i64,
+    /// Elapsed time
+    time: f64,
+}
+
+impl DrivenLatticeGas {
+    /// Create new driven lattice gas from an initial state
+    pub fn new(state: SquareLattice2D<Occupancy>, coupling: f64, beta: f64, field: f64) -> Self {
+        Self {
+            state,
+            coupling,
+            beta,
+            field,
+            net_hops: 0,
+            time: 0.0,
+        }
+    }
+
+    /// Mean particle current along the field per site since the last reset,
+    /// zero before any time elapsed
+    pub fn current(&self) -> f64 {
+        if self.time <= 0.0 {
+            return 0.0;
+        }
+        self.net_hops as f64 / (self.time * self.state.site_count() as f64)
+    }
+
+    /// Number of occupied nearest neighbors of a site
+    #[inline(always)]
+    fn occupied_neighbors(&self, idx: [usize; 2]) -> i32 {
+        self.state
+            .nearest_neighbors(idx)
+            .filter(|s| s.is_occupied())
+            .count() as i32
+    }
+
+    /// Single exchange attempt
+    fn event<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let period = &self.state.period;
+        let idx @ [i, j] = self.state.sample(rng);
+        let (nn_idx, delta) = match rng.gen_range(0..4) {
+            0 => ([i, period.next(j)], 1),
+            1 => ([i, period.prev(j)], -1),
+            2 => ([period.next(i), j], 0),
+            _ => ([period.prev(i), j], 0),
+        };
+        let (from, to, delta) = match (self.state[idx], self.state[nn_idx]) {
+            (Occupancy::Occupied, Occupancy::Empty) => (idx, nn_idx, delta),
+            (Occupancy::Empty, Occupancy::Occupied) => (nn_idx, idx, -delta),
+            _ => return,
+        };
+        // The moving particle stops interacting with its old neighbors and the vacated site
+        let delta_energy = -self.coupling
+            * ((self.occupied_neighbors(to) - 1) - self.occupied_neighbors(from)) as f64;
+        let exponent = -self.beta * (delta_energy - self.field * delta as f64);
+        if exponent >= 0.0 || rng.r#gen::<f64>() < f64::exp(exponent) {
+            self.state.state.swap(from, to);
+            self.net_hops += delta as i64;
+        }
+    }
+}
+
+impl StochasticProcess for DrivenLatticeGas {
+    type State = SquareLattice2D<Occupancy>;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    #[inline(always)]
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+    }
+
+    fn reset<I: InitialStateSpec<Self::State>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.net_hops = 0;
+        self.time = 0.0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// One Monte Carlo step of one exchange attempt per site
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for _ in 0..self.state.site_count() {
+            self.event(rng);
+        }
+        self.time += 1.0;
+    }
+}
+
+/// Time-averaged occupation of each site
+#[derive(Debug, Clone, Default)]
+pub struct DensityProfile {
+    /// Accumulated occupation of each site in linear order
+    occupation: Vec<u64>,
+    /// Number of recorded states
+    samples: u64,
+}
+
+impl DensityProfile {
+    /// Create empty profile
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulate the occupation of a state
+    pub fn record<S: SiteState<Site = Occupancy>>(&mut self, state: &S) {
+        self.occupation.resize(state.site_count(), 0);
+        for (n, s) in self.occupation.iter_mut().zip(state.sites()) {
+            *n += s.is_occupied() as u64;
+        }
+        self.samples += 1;
+    }
+
+    /// Mean density of each site in linear order
+    pub fn profile(&self) -> Vec<f64> {
+        self.occupation
+            .iter()
+            .map(|&n| n as f64 / self.samples as f64)
+            .collect()
+    }
+}
@@ -0,0 +1,133 @@
+//! Forest-Fire Model
+//!
+//! Drossel & Schwabl, Phys. Rev. Lett. 69, 1629 (1992)
+
+use super::MonteCarloUpdate;
+use crate::states::{SiteCharRepr, SiteState, SiteStateNN, StateMeasurement};
+use rand::Rng;
+use rand_distr::{Bernoulli, Distribution};
+
+/// Forest site
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ForestSite {
+    #[default]
+    Empty,
+    Tree,
+    Burning,
+}
+
+impl SiteCharRepr for ForestSite {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            ForestSite::Empty => ' ',
+            ForestSite::Tree => '♣',
+            ForestSite::Burning => '*',
+        }
+    }
+}
+
+/// Forest-fire update
+/// Each visited site:
+/// - Grows a tree with the growth coin probability if empty
+/// - Is struck by lightning with the lightning coin probability if it holds a tree,
+///   burning down its whole tree cluster instantly
+#[derive(Debug, Clone, Copy)]
+pub struct ForestFire {
+    /// Tree growth coin $p$
+    pub growth_coin: Bernoulli,
+    /// Lightning coin $f$
+    pub lightning_coin: Bernoulli,
+}
+
+impl ForestFire {
+    /// Perform a single Monte Carlo step and return the size of each fire
+    pub fn update<S, R>(&self, state: &mut S, rng: &mut R) -> Vec<usize>
+    where
+        S: SiteStateNN<Site = ForestSite>,
+        R: Rng + ?Sized,
+    {
+        let mut fires = Vec::new();
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            match state[idx] {
+                ForestSite::Empty => {
+                    if self.growth_coin.sample(rng) {
+                        state[idx] = ForestSite::Tree;
+                    }
+                }
+                ForestSite::Tree => {
+                    if self.lightning_coin.sample(rng) {
+                        fires.push(burn_cluster(state, idx));
+                    }
+                }
+                ForestSite::Burning => {}
+            }
+        }
+        fires
+    }
+
+    /// Perform `steps` Monte Carlo steps and return the sizes of all fires
+    pub fn run<S, R>(&self, state: &mut S, steps: usize, rng: &mut R) -> Vec<usize>
+    where
+        S: SiteStateNN<Site = ForestSite>,
+        R: Rng + ?Sized,
+    {
+        (0..steps).flat_map(|_| self.update(state, rng)).collect()
+    }
+}
+
+impl<S> MonteCarloUpdate<S> for ForestFire
+where
+    S: SiteStateNN<Site = ForestSite>,
+{
+    #[inline(always)]
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        self.update(state, rng);
+    }
+}
+
+/// Burn the tree cluster containing a given site by flood fill and return its size
+///
+/// Trees are first marked as burning while the fire spreads and then removed.
+pub fn burn_cluster<S>(state: &mut S, idx: S::Index) -> usize
+where
+    S: SiteStateNN<Site = ForestSite>,
+{
+    if state[idx] != ForestSite::Tree {
+        return 0;
+    }
+    state[idx] = ForestSite::Burning;
+    let mut front = vec![idx];
+    let mut burnt = Vec::new();
+    while let Some(idx) = front.pop() {
+        burnt.push(idx);
+        let neighbors: Vec<S::Index> = state.nearest_neighbors_index(idx).collect();
+        for nn_idx in neighbors {
+            if state[nn_idx] == ForestSite::Tree {
+                state[nn_idx] = ForestSite::Burning;
+                front.push(nn_idx);
+            }
+        }
+    }
+    for &idx in &burnt {
+        state[idx] = ForestSite::Empty;
+    }
+    burnt.len()
+}
+
+/// Density of trees
+#[derive(Debug, Clone, Copy)]
+pub struct TreeDensity;
+
+impl<S> StateMeasurement<S> for TreeDensity
+where
+    S: SiteState<Site = ForestSite>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let trees = state.sites().filter(|&&s| s == ForestSite::Tree).count();
+        trees as f64 / state.site_count() as f64
+    }
+}
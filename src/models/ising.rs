@@ -0,0 +1,92 @@
+//! Ising Model
+//!
+
+use super::MonteCarloUpdate;
+use crate::states::{SiteState, SiteStateNN, StateMeasurement, sites::IsingSpin};
+use rand::{Rng, seq::IteratorRandom};
+
+/// Magnetization per site $m = \sum_i \sigma_i / N$
+#[derive(Debug, Clone, Copy)]
+pub struct Magnetization;
+
+impl<S> StateMeasurement<S> for Magnetization
+where
+    S: SiteState<Site = IsingSpin>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let sum: i32 = state.sites().map(|s| s.value()).sum();
+        sum as f64 / state.site_count() as f64
+    }
+}
+
+/// Energy per site $e = -J \sum_{\langle i,j \rangle} \sigma_i \sigma_j / N$
+#[derive(Debug, Clone, Copy)]
+pub struct Energy {
+    /// Coupling constant $J$
+    pub coupling: f64,
+}
+
+impl<S> StateMeasurement<S> for Energy
+where
+    S: SiteStateNN<Site = IsingSpin>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let sum: i32 = state
+            .nearest_neighbors_pairs()
+            .map(|(a, b)| a.value() * b.value())
+            .sum();
+        -self.coupling * sum as f64 / state.site_count() as f64
+    }
+}
+
+/// Kawasaki spin-exchange dynamics conserving the magnetization
+/// Each attempt picks a random site and a random nearest neighbor
+/// and swaps their spins with Metropolis probability $\min(1, e^{-\beta \Delta E})$.
+#[derive(Debug, Clone, Copy)]
+pub struct Kawasaki {
+    /// Inverse temperature $\beta$
+    pub beta: f64,
+    /// Coupling constant $J$
+    pub coupling: f64,
+}
+
+impl Kawasaki {
+    /// Energy change of swapping the spins of nearest neighbors `a` and `b`
+    fn delta_energy<S>(&self, state: &S, a: S::Index, b: S::Index) -> f64
+    where
+        S: SiteStateNN<Site = IsingSpin>,
+    {
+        let (sa, sb) = (state[a].value(), state[b].value());
+        // Local fields excluding the exchanged partner, whose bond is unchanged
+        let field_a: i32 = state.nearest_neighbors(a).map(|s| s.value()).sum::<i32>() - sb;
+        let field_b: i32 = state.nearest_neighbors(b).map(|s| s.value()).sum::<i32>() - sa;
+        -self.coupling * ((sb - sa) * field_a + (sa - sb) * field_b) as f64
+    }
+}
+
+impl<S> MonteCarloUpdate<S> for Kawasaki
+where
+    S: SiteStateNN<Site = IsingSpin>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            let a = state.sample(rng);
+            let Some(b) = state.nearest_neighbors_index(a).choose(rng) else {
+                continue;
+            };
+            if state[a] == state[b] {
+                continue;
+            }
+            let delta_energy = self.delta_energy(state, a, b);
+            if delta_energy <= 0.0 || rng.r#gen::<f64>() < f64::exp(-self.beta * delta_energy) {
+                let sa = state[a];
+                state[a] = state[b];
+                state[b] = sa;
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Stochastic Lattice Lotka–Volterra Model
+//!
+//! Prey and predators with at most one individual per site, showing erratic population
+//! oscillations with an amplitude vanishing as $N^{-1/2}$ in large systems.
+//!
+//! Mobilia, Georgiev & Täuber, J. Stat. Phys. 128, 447 (2007)
+
+use super::MonteCarloUpdate;
+use crate::states::{SiteCharRepr, SiteState, SiteStateNN, StateMeasurement};
+use rand::{Rng, seq::IteratorRandom};
+
+/// Lotka–Volterra site
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Animal {
+    #[default]
+    Empty,
+    Prey,
+    Predator,
+}
+
+impl SiteCharRepr for Animal {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            Animal::Empty => ' ',
+            Animal::Prey => '·',
+            Animal::Predator => '█',
+        }
+    }
+}
+
+/// Lotka–Volterra update
+/// Each event selects a random site and a random nearest neighbor, and performs one of:
+/// - Predator death with rate $\mu$: the predator leaves an empty site
+/// - Prey birth with rate $\sigma$: the prey places an offspring on an empty neighbor
+/// - Predation with rate $\lambda$: the predator replaces a neighboring prey by an offspring
+/// - Diffusion with rate $D$: swap contents, disabled for $D = 0$
+///
+/// A Monte Carlo step consists of $N$ events, one of which is chosen proportionally to its rate.
+#[derive(Debug, Clone, Copy)]
+pub struct LotkaVolterra {
+    /// Predator death rate $\mu$
+    pub death_rate: f64,
+    /// Prey birth rate $\sigma$
+    pub birth_rate: f64,
+    /// Predation rate $\lambda$
+    pub predation_rate: f64,
+    /// Diffusion rate $D$
+    pub diffusion_rate: f64,
+}
+
+impl LotkaVolterra {
+    /// Create update without diffusion from rates $\mu$, $\sigma$ and $\lambda$
+    ///
+    /// Panics if a rate is negative or all rates vanish.
+    pub fn new(death_rate: f64, birth_rate: f64, predation_rate: f64) -> Self {
+        assert!(
+            death_rate >= 0.0 && birth_rate >= 0.0 && predation_rate >= 0.0,
+            "Rates must be non-negative"
+        );
+        assert!(
+            death_rate + birth_rate + predation_rate > 0.0,
+            "At least one rate must be positive"
+        );
+        Self {
+            death_rate,
+            birth_rate,
+            predation_rate,
+            diffusion_rate: 0.0,
+        }
+    }
+
+    /// Use a given diffusion rate $D$
+    ///
+    /// Panics if the rate is negative.
+    pub fn with_diffusion(mut self, diffusion_rate: f64) -> Self {
+        assert!(diffusion_rate >= 0.0, "Diffusion rate must be non-negative");
+        self.diffusion_rate = diffusion_rate;
+        self
+    }
+
+    /// Perform a single event
+    fn event<S, R>(&self, state: &mut S, rng: &mut R)
+    where
+        S: SiteStateNN<Site = Animal>,
+        R: Rng + ?Sized,
+    {
+        let idx = state.sample(rng);
+        let Some(nn_idx) = state.nearest_neighbors_index(idx).choose(rng) else {
+            return;
+        };
+        let (a, b) = (state[idx], state[nn_idx]);
+        let total_rate =
+            self.death_rate + self.birth_rate + self.predation_rate + self.diffusion_rate;
+        let mut u = rng.gen_range(0.0..total_rate);
+        // Predator death
+        if u < self.death_rate {
+            if a == Animal::Predator {
+                state[idx] = Animal::Empty;
+            }
+            return;
+        }
+        u -= self.death_rate;
+        // Prey birth
+        if u < self.birth_rate {
+            if a == Animal::Prey && b == Animal::Empty {
+                state[nn_idx] = Animal::Prey;
+            }
+            return;
+        }
+        u -= self.birth_rate;
+        // Predation
+        if u < self.predation_rate {
+            if a == Animal::Predator && b == Animal::Prey {
+                state[nn_idx] = Animal::Predator;
+            }
+            return;
+        }
+        // Diffusion
+        state[idx] = b;
+        state[nn_idx] = a;
+    }
+
+    /// Perform `steps` Monte Carlo steps recording the densities before each step and at the end
+    pub fn time_series<S, R>(
+        &mut self,
+        state: &mut S,
+        steps: usize,
+        rng: &mut R,
+    ) -> PopulationSeries
+    where
+        S: SiteStateNN<Site = Animal>,
+        R: Rng + ?Sized,
+    {
+        let mut series = PopulationSeries::default();
+        for t in 0..=steps {
+            if t > 0 {
+                self.step(state, rng);
+            }
+            let (prey, predators) = PopulationDensities.measure(state);
+            series.prey.push(prey);
+            series.predators.push(predators);
+        }
+        series
+    }
+}
+
+impl<S> MonteCarloUpdate<S> for LotkaVolterra
+where
+    S: SiteStateNN<Site = Animal>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            self.event(state, rng);
+        }
+    }
+}
+
+/// Densities of prey and predators
+#[derive(Debug, Clone, Copy)]
+pub struct PopulationDensities;
+
+impl<S> StateMeasurement<S> for PopulationDensities
+where
+    S: SiteState<Site = Animal>,
+{
+    type Type = (f64, f64);
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let (mut prey, mut predators) = (0, 0);
+        for s in state.sites() {
+            match s {
+                Animal::Prey => prey += 1,
+                Animal::Predator => predators += 1,
+                Animal::Empty => {}
+            }
+        }
+        let n = state.site_count() as f64;
+        (prey as f64 / n, predators as f64 / n)
+    }
+}
+
+/// Population densities indexed by Monte Carlo step
+#[derive(Debug, Clone, Default)]
+pub struct PopulationSeries {
+    /// Prey density
+    pub prey: Vec<f64>,
+    /// Predator density
+    pub predators: Vec<f64>,
+}
+
+impl PopulationSeries {
+    /// Oscillation amplitudes of the prey and predator densities
+    /// measured by their standard deviations after discarding a transient of `transient` steps
+    pub fn amplitudes(&self, transient: usize) -> (f64, f64) {
+        let std_dev = |series: &[f64]| {
+            let series = series.get(transient..).unwrap_or_default();
+            if series.is_empty() {
+                return 0.0;
+            }
+            let n = series.len() as f64;
+            let mean = series.iter().sum::<f64>() / n;
+            (series.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt()
+        };
+        (std_dev(&self.prey), std_dev(&self.predators))
+    }
+
+    /// Check if the predators went extinct
+    #[inline(always)]
+    pub fn predators_extinct(&self) -> bool {
+        self.predators.last().is_some_and(|&rho| rho == 0.0)
+    }
+}
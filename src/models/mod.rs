@@ -0,0 +1,130 @@
+//! Models
+//!
+
+use crate::states::{InitialStateSpec, SiteState};
+use rand::Rng;
+
+/// Number of items handled by each parallel task
+/// Fixed so that results depend only on the seed and not on the thread count
+pub(crate) const PARALLEL_CHUNK_SIZE: usize = 4096;
+
+/// Draw one seed for each parallel chunk of `count` items
+pub(crate) fn chunk_seeds<R: Rng + ?Sized>(count: usize, rng: &mut R) -> Vec<u64> {
+    (0..count.div_ceil(PARALLEL_CHUNK_SIZE))
+        .map(|_| rng.r#gen())
+        .collect()
+}
+
+/// Monte Carlo update acting on a state
+pub trait MonteCarloUpdate<S: SiteState> {
+    /// Perform a single Monte Carlo step
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R);
+}
+
+/// Stochastic process evolving a state it owns
+pub trait StochasticProcess {
+    /// Type of the evolved state
+    type State: SiteState;
+
+    /// Current state
+    fn state(&self) -> &Self::State;
+
+    /// Replace the current state keeping the elapsed time
+    fn set_state(&mut self, state: Self::State);
+
+    /// Reset state with a given specification and restart the clock
+    fn reset<I: InitialStateSpec<Self::State>>(&mut self, spec: &mut I);
+
+    /// Elapsed time
+    fn time(&self) -> f64;
+
+    /// Advance the process by one unit of time
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R);
+}
+
+/// Stochastic process with an absorbing state
+pub trait AbsorbingProcess: StochasticProcess {
+    /// Number of active sites
+    fn active_count(&self) -> usize;
+
+    /// Iterator over the linear indices of the active sites
+    fn active_indices(&self) -> impl Iterator<Item = usize>;
+
+    /// Check if the absorbing state was reached
+    #[inline(always)]
+    fn is_absorbed(&self) -> bool {
+        self.active_count() == 0
+    }
+
+    /// Run until absorption or until `max_time` is reached
+    /// Returns whether the absorbing state was reached
+    fn run_until_absorbed<R: Rng + ?Sized>(&mut self, max_time: f64, rng: &mut R) -> bool {
+        while !self.is_absorbed() && self.time() < max_time {
+            self.step(rng);
+        }
+        self.is_absorbed()
+    }
+}
+
+/// Ising model
+pub mod ising;
+
+/// Swendsen–Wang multi-cluster algorithm
+pub mod swendsen_wang;
+
+/// Potts model
+pub mod potts;
+
+/// Contact process
+pub mod contact_process;
+
+/// Quasi-stationary simulation method
+pub mod quasi_stationary;
+
+/// Spreading experiments
+pub mod spreading;
+
+/// Space-time recording
+pub mod space_time;
+
+/// Directed percolation
+pub mod directed_percolation;
+
+/// Voter model
+pub mod voter;
+
+/// Epidemic models
+pub mod epidemics;
+
+/// Cellular automata
+pub mod cellular_automata;
+
+/// Sandpile models
+pub mod sandpile;
+
+/// Forest-fire model
+pub mod forest_fire;
+
+/// Exclusion processes
+pub mod exclusion;
+
+/// Reaction–diffusion processes
+pub mod reaction_diffusion;
+
+/// Zero-range and inclusion processes
+pub mod zero_range;
+
+/// Cyclic dominance
+pub mod cyclic_dominance;
+
+/// Lattice Lotka–Volterra model
+pub mod lotka_volterra;
+
+/// Schelling segregation model
+pub mod schelling;
+
+/// Axelrod model of cultural dissemination
+pub mod axelrod;
+
+/// Bounded confidence opinion dynamics
+pub mod bounded_confidence;
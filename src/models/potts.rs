@@ -0,0 +1,292 @@
+//! Potts Model
+//!
+
+use super::{MonteCarloUpdate, swendsen_wang::SwendsenWang};
+use crate::states::{
+    SiteState, SiteStateNN, StateMeasurement,
+    clusters::Clusters,
+    sites::{PottsSpin, PottsStates},
+};
+use rand::{Rng, seq::index};
+use rand_distr::{Bernoulli, Distribution, WeightedIndex};
+
+/// Count the nearest neighbors of a site in each of the `q` states
+#[inline(always)]
+fn neighbor_counts<S>(state: &S, idx: S::Index, q: u8) -> Vec<u32>
+where
+    S: SiteStateNN<Site = PottsSpin>,
+{
+    let mut counts = vec![0; q as usize];
+    for s in state.nearest_neighbors(idx) {
+        counts[s.0 as usize] += 1;
+    }
+    counts
+}
+
+/// Bond probability $p = 1 - e^{-\beta J}$ of the Fortuin–Kasteleyn representation
+///
+/// Panics if `beta * coupling` is negative.
+fn fk_bond_coin(beta: f64, coupling: f64) -> Bernoulli {
+    Bernoulli::new(1.0 - f64::exp(-beta * coupling))
+        .expect("Potts bond probability must lie in [0, 1]")
+}
+
+/// Parameters of the $q$-state Potts model
+/// $H = -J \sum_{\langle i,j \rangle} \delta_{\sigma_i \sigma_j}$
+#[derive(Debug, Clone, Copy)]
+pub struct Potts {
+    /// Number of states
+    pub q: u8,
+    /// Coupling constant $J$
+    pub coupling: f64,
+    /// Inverse temperature $\beta$
+    pub beta: f64,
+}
+
+impl Potts {
+    /// Uniform distribution over the spin states
+    #[inline(always)]
+    pub fn states(&self) -> PottsStates {
+        PottsStates::new(self.q)
+    }
+
+    /// Heat-bath single-spin update
+    pub fn heat_bath(&self) -> HeatBath {
+        HeatBath { model: *self }
+    }
+
+    /// Metropolis single-spin update
+    pub fn metropolis(&self) -> Metropolis {
+        Metropolis { model: *self }
+    }
+
+    /// Swendsen–Wang cluster update
+    pub fn swendsen_wang(&self) -> SwendsenWang<PottsStates> {
+        SwendsenWang::new(fk_bond_coin(self.beta, self.coupling), self.states())
+    }
+
+    /// Chayes–Machta cluster update with `active_colors` active colors
+    pub fn chayes_machta(&self, active_colors: u8) -> ChayesMachta {
+        ChayesMachta::new(*self, active_colors)
+    }
+
+    /// Order parameter measurement
+    ///
+    /// Panics if there are fewer than two states.
+    pub fn order_parameter(&self) -> OrderParameter {
+        OrderParameter::new(self.q)
+    }
+
+    /// Energy per site measurement
+    pub fn energy(&self) -> Energy {
+        Energy {
+            coupling: self.coupling,
+        }
+    }
+
+    /// Energy change $\Delta E = -J (n_{new} - n_{old})$ of setting the spin at `idx` to `new`,
+    /// where $n_k$ is the number of its nearest neighbors in state $k$
+    #[inline(always)]
+    pub fn delta_energy<S>(&self, state: &S, idx: S::Index, new: PottsSpin) -> f64
+    where
+        S: SiteStateNN<Site = PottsSpin>,
+    {
+        let old = state[idx];
+        let (n_old, n_new) = state
+            .nearest_neighbors(idx)
+            .fold((0, 0), |(n_old, n_new), s| {
+                (n_old + (*s == old) as i32, n_new + (*s == new) as i32)
+            });
+        -self.coupling * (n_new - n_old) as f64
+    }
+}
+
+/// Heat-bath update
+/// Each visited spin is redrawn with probability proportional to $e^{\beta J n_k}$,
+/// where $n_k$ is the number of its nearest neighbors in state $k$.
+#[derive(Debug, Clone, Copy)]
+pub struct HeatBath {
+    model: Potts,
+}
+
+impl<S> MonteCarloUpdate<S> for HeatBath
+where
+    S: SiteStateNN<Site = PottsSpin>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        let Potts { q, coupling, beta } = self.model;
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            let exponents: Vec<f64> = neighbor_counts(state, idx, q)
+                .into_iter()
+                .map(|n| beta * coupling * n as f64)
+                .collect();
+            // Shift by the largest exponent to keep the weights finite at low temperature
+            let max = exponents.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let weights = exponents.into_iter().map(|e| f64::exp(e - max));
+            let dist = WeightedIndex::new(weights).expect("Heat-bath weights must be finite");
+            state[idx] = PottsSpin(dist.sample(rng) as u8);
+        }
+    }
+}
+
+/// Metropolis update
+/// Each visited spin proposes a different uniformly chosen state,
+/// accepted with probability $\min(1, e^{-\beta \Delta E})$.
+#[derive(Debug, Clone, Copy)]
+pub struct Metropolis {
+    model: Potts,
+}
+
+impl<S> MonteCarloUpdate<S> for Metropolis
+where
+    S: SiteStateNN<Site = PottsSpin>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        let Potts { q, beta, .. } = self.model;
+        if q < 2 {
+            return;
+        }
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            let PottsSpin(old) = state[idx];
+            // Uniform over the other q - 1 states
+            let new = PottsSpin(((old as u16 + rng.gen_range(1..q as u16)) % q as u16) as u8);
+            let delta_energy = self.model.delta_energy(state, idx, new);
+            if delta_energy <= 0.0 || rng.r#gen::<f64>() < f64::exp(-beta * delta_energy) {
+                state[idx] = new;
+            }
+        }
+    }
+}
+
+/// Chayes–Machta cluster update
+/// - Choose `active_colors` distinct colors at random
+/// - Activate Fortuin–Kasteleyn bonds only between equal neighbors with an active color
+/// - Recolor each cluster of active sites uniformly among the active colors
+///
+/// With two active colors this is the embedded Ising cluster update
+/// and with all $q$ colors active it reduces to Swendsen–Wang.
+#[derive(Debug, Clone)]
+pub struct ChayesMachta {
+    q: u8,
+    active_colors: u8,
+    sw: SwendsenWang<PottsStates>,
+}
+
+impl ChayesMachta {
+    /// Create new update for a given model
+    ///
+    /// Panics unless `1 <= active_colors <= q`.
+    pub fn new(model: Potts, active_colors: u8) -> Self {
+        assert!(
+            (1..=model.q).contains(&active_colors),
+            "Number of active colors must lie in 1..=q"
+        );
+        Self {
+            q: model.q,
+            active_colors,
+            sw: model.swendsen_wang(),
+        }
+    }
+
+    /// Perform a single update and return the clusters of the bond configuration
+    ///
+    /// Inactive sites are left as singleton clusters.
+    pub fn update<S, R>(&self, state: &mut S, rng: &mut R) -> Clusters
+    where
+        S: SiteStateNN<Site = PottsSpin>,
+        R: Rng + ?Sized,
+    {
+        let active: Vec<u8> = index::sample(rng, self.q as usize, self.active_colors as usize)
+            .into_iter()
+            .map(|c| c as u8)
+            .collect();
+        let is_active = |s: &PottsSpin| active.contains(&s.0);
+        let clusters = self.sw.activate_bonds(state, is_active, rng).clusters();
+        // Recolor active clusters
+        let mut cluster_colors: Vec<Option<u8>> = vec![None; clusters.count()];
+        for (k, &label) in clusters.labels.iter().enumerate() {
+            let idx = state.site_index(k);
+            if is_active(&state[idx]) {
+                let color = *cluster_colors[label]
+                    .get_or_insert_with(|| active[rng.gen_range(0..active.len())]);
+                state[idx] = PottsSpin(color);
+            }
+        }
+        clusters
+    }
+}
+
+impl<S> MonteCarloUpdate<S> for ChayesMachta
+where
+    S: SiteStateNN<Site = PottsSpin>,
+{
+    #[inline(always)]
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        self.update(state, rng);
+    }
+}
+
+/// Potts order parameter $m = (q \max_k \rho_k - 1) / (q - 1)$,
+/// where $\rho_k$ is the fraction of sites in state $k$
+#[derive(Debug, Clone, Copy)]
+pub struct OrderParameter {
+    /// Number of states
+    q: u8,
+}
+
+impl OrderParameter {
+    /// Create measurement for $q$ states
+    ///
+    /// Panics if there are fewer than two states.
+    pub fn new(q: u8) -> Self {
+        assert!(q >= 2, "Potts order parameter requires at least two states");
+        Self { q }
+    }
+
+    /// Number of states
+    #[inline(always)]
+    pub fn q(&self) -> u8 {
+        self.q
+    }
+}
+
+impl<S> StateMeasurement<S> for OrderParameter
+where
+    S: SiteState<Site = PottsSpin>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let q = self.q as usize;
+        let mut counts = vec![0usize; q];
+        for s in state.sites() {
+            counts[s.0 as usize] += 1;
+        }
+        let rho_max = counts.into_iter().max().unwrap_or(0) as f64 / state.site_count() as f64;
+        (q as f64 * rho_max - 1.0) / (q as f64 - 1.0)
+    }
+}
+
+/// Energy per site $e = -J \sum_{\langle i,j \rangle} \delta_{\sigma_i \sigma_j} / N$
+#[derive(Debug, Clone, Copy)]
+pub struct Energy {
+    /// Coupling constant $J$
+    pub coupling: f64,
+}
+
+impl<S> StateMeasurement<S> for Energy
+where
+    S: SiteStateNN<Site = PottsSpin>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let aligned = state
+            .nearest_neighbors_pairs()
+            .filter(|(a, b)| a == b)
+            .count();
+        -self.coupling * aligned as f64 / state.site_count() as f64
+    }
+}
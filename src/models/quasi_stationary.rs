@@ -0,0 +1,135 @@
+//! Quasi-Stationary Simulation Method
+//!
+//! de Oliveira & Dickman, Phys. Rev. E 71, 016129 (2005)
+
+use super::AbsorbingProcess;
+use crate::states::SiteState;
+use rand::Rng;
+use rand_distr::{Bernoulli, Distribution};
+
+/// Quasi-stationary averages
+#[derive(Debug, Clone, Copy)]
+pub struct QuasiStationaryStats {
+    /// Mean density of active sites $\langle \rho \rangle$
+    pub mean_density: f64,
+    /// Mean squared density of active sites $\langle \rho^2 \rangle$
+    pub mean_squared_density: f64,
+    /// Number of absorption events during the measurement
+    pub absorptions: usize,
+    /// Mean time between absorption events $\tau$ in process time,
+    /// `None` if no absorption occurred during the measurement
+    pub lifetime: Option<f64>,
+}
+
+impl QuasiStationaryStats {
+    /// Moment ratio $\langle \rho^2 \rangle / \langle \rho \rangle^2$
+    #[inline(always)]
+    pub fn moment_ratio(&self) -> f64 {
+        self.mean_squared_density / (self.mean_density * self.mean_density)
+    }
+}
+
+/// Quasi-stationary simulation of an absorbing process
+/// - Keep a history of active configurations
+/// - Replace a random saved configuration with the current one with a given probability each unit of time
+/// - Upon absorption restore a uniformly chosen saved configuration
+#[derive(Debug, Clone)]
+pub struct QuasiStationary<P>
+where
+    P: AbsorbingProcess,
+    P::State: Clone,
+{
+    /// Underlying process
+    process: P,
+    /// Saved active configurations
+    history: Vec<P::State>,
+    /// Coin for replacing a saved configuration
+    replacement_coin: Bernoulli,
+    /// Total number of absorption events
+    absorptions: usize,
+}
+
+impl<P> QuasiStationary<P>
+where
+    P: AbsorbingProcess,
+    P::State: Clone,
+{
+    /// Create new simulation keeping `history_size` configurations
+    ///
+    /// The history is initially filled with the current state of the process,
+    /// which must therefore be active.
+    ///
+    /// Panics if the process is absorbed or `history_size` is zero.
+    pub fn new(process: P, history_size: usize, replacement_coin: Bernoulli) -> Self {
+        assert!(
+            !process.is_absorbed(),
+            "Quasi-stationary history requires an active initial state"
+        );
+        assert!(
+            history_size > 0,
+            "History must hold at least one configuration"
+        );
+        let history = vec![process.state().clone(); history_size];
+        Self {
+            process,
+            history,
+            replacement_coin,
+            absorptions: 0,
+        }
+    }
+
+    /// Underlying process
+    #[inline(always)]
+    pub fn process(&self) -> &P {
+        &self.process
+    }
+
+    /// Total number of absorption events
+    #[inline(always)]
+    pub fn absorptions(&self) -> usize {
+        self.absorptions
+    }
+
+    /// Advance the process by one unit of time, restoring a saved configuration upon absorption
+    pub fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.process.step(rng);
+        if self.process.is_absorbed() {
+            self.absorptions += 1;
+            let k = rng.gen_range(0..self.history.len());
+            self.process.set_state(self.history[k].clone());
+        } else if self.replacement_coin.sample(rng) {
+            let k = rng.gen_range(0..self.history.len());
+            self.history[k].clone_from(self.process.state());
+        }
+    }
+
+    /// Relax for `relaxation_time` and then measure quasi-stationary averages for `measurement_time`
+    pub fn run<R: Rng + ?Sized>(
+        &mut self,
+        relaxation_time: usize,
+        measurement_time: usize,
+        rng: &mut R,
+    ) -> QuasiStationaryStats {
+        for _ in 0..relaxation_time {
+            self.step(rng);
+        }
+        let absorptions_start = self.absorptions;
+        let time_start = self.process.time();
+        let site_count = self.process.state().site_count() as f64;
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..measurement_time {
+            self.step(rng);
+            let rho = self.process.active_count() as f64 / site_count;
+            sum += rho;
+            sum_sq += rho * rho;
+        }
+        let absorptions = self.absorptions - absorptions_start;
+        let elapsed = self.process.time() - time_start;
+        QuasiStationaryStats {
+            mean_density: sum / measurement_time as f64,
+            mean_squared_density: sum_sq / measurement_time as f64,
+            absorptions,
+            lifetime: (absorptions > 0).then(|| elapsed / absorptions as f64),
+        }
+    }
+}
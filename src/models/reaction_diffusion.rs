@@ -0,0 +1,427 @@
+//! Reaction–Diffusion Processes
+//!
+//! Particles living on the sites of a lattice react according to a reaction table
+//! and hop by swap diffusion between reaction steps:
+//! - Diffusion-limited annihilation $A + A \to 0$ and coagulation $A + A \to A$, $\rho \sim t^{-d/2}$ for $d < 2$
+//! - Two-species annihilation $A + B \to 0$, $\rho \sim t^{-d/4}$ for $d < 4$
+//! - Branching-annihilating walks $A \to (1 + n) A$, $A + A \to 0$,
+//!   in the directed percolation class for odd $n$ and in the parity-conserving class for even $n$
+
+use super::{AbsorbingProcess, StochasticProcess};
+use crate::states::{
+    InitialStateSpec, SimpleSwapDiffusion, SiteState, SiteStateNN, StateMeasurement,
+    active_sites::ActiveSites,
+    sites::{Occupancy, Particle},
+};
+use rand::{Rng, seq::IteratorRandom};
+use rand_distr::Bernoulli;
+
+/// Spontaneous conversion $X \to Y$ of a single particle, e.g. decay $A \to 0$
+#[derive(Debug, Clone, Copy)]
+pub struct Conversion<T> {
+    /// Converted species
+    pub from: T,
+    /// Resulting site state
+    pub into: T,
+    /// Conversion rate
+    pub rate: f64,
+}
+
+/// Reaction $X + Y \to X' + Y'$ of a particle with a random nearest neighbor
+///
+/// Reactants match in either order, in which case the products are placed in the same order.
+#[derive(Debug, Clone, Copy)]
+pub struct PairReaction<T> {
+    /// States of the particle and its neighbor
+    pub reactants: [T; 2],
+    /// States replacing the reactants
+    pub products: [T; 2],
+    /// Reaction rate
+    pub rate: f64,
+}
+
+/// Branching $X \to (1 + n) X$ placing $n$ offspring on distinct random nearest neighbors
+#[derive(Debug, Clone, Copy)]
+pub struct Branching<T> {
+    /// Branching species
+    pub parent: T,
+    /// Number of offspring $n$
+    pub offspring: usize,
+    /// Branching rate
+    pub rate: f64,
+}
+
+/// Instantaneous outcome of an offspring arriving on an occupied site
+#[derive(Debug, Clone, Copy)]
+pub struct Encounter<T> {
+    /// Species of the offspring
+    pub arriving: T,
+    /// State of the occupied site
+    pub resident: T,
+    /// Resulting site state
+    pub result: T,
+}
+
+/// Reaction table
+/// Sites in the default state are empty, all other sites hold a particle.
+/// Offspring arriving on an occupied site without a matching encounter are blocked.
+#[derive(Debug, Clone)]
+pub struct ReactionTable<T> {
+    /// Single particle conversions
+    pub conversions: Vec<Conversion<T>>,
+    /// Nearest neighbor pair reactions
+    pub pair_reactions: Vec<PairReaction<T>>,
+    /// Branching reactions
+    pub branchings: Vec<Branching<T>>,
+    /// Encounters of offspring with resident particles
+    pub encounters: Vec<Encounter<T>>,
+}
+
+impl<T> Default for ReactionTable<T> {
+    fn default() -> Self {
+        Self {
+            conversions: Vec::new(),
+            pair_reactions: Vec::new(),
+            branchings: Vec::new(),
+            encounters: Vec::new(),
+        }
+    }
+}
+
+impl<T> ReactionTable<T>
+where
+    T: Clone + Copy + PartialEq + Default,
+{
+    /// Create empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add conversion $X \to Y$
+    pub fn with_conversion(mut self, from: T, into: T, rate: f64) -> Self {
+        self.conversions.push(Conversion { from, into, rate });
+        self
+    }
+
+    /// Add pair reaction $X + Y \to X' + Y'$
+    pub fn with_pair_reaction(mut self, reactants: [T; 2], products: [T; 2], rate: f64) -> Self {
+        self.pair_reactions.push(PairReaction {
+            reactants,
+            products,
+            rate,
+        });
+        self
+    }
+
+    /// Add branching $X \to (1 + n) X$
+    pub fn with_branching(mut self, parent: T, offspring: usize, rate: f64) -> Self {
+        self.branchings.push(Branching {
+            parent,
+            offspring,
+            rate,
+        });
+        self
+    }
+
+    /// Add encounter of an arriving offspring with a resident particle
+    pub fn with_encounter(mut self, arriving: T, resident: T, result: T) -> Self {
+        self.encounters.push(Encounter {
+            arriving,
+            resident,
+            result,
+        });
+        self
+    }
+
+    /// Sum of all reaction rates
+    pub fn total_rate(&self) -> f64 {
+        self.conversions.iter().map(|r| r.rate).sum::<f64>()
+            + self.pair_reactions.iter().map(|r| r.rate).sum::<f64>()
+            + self.branchings.iter().map(|r| r.rate).sum::<f64>()
+    }
+
+    /// State of a site after an offspring arrives, `None` if blocked
+    fn arrive(&self, arriving: T, resident: T) -> Option<T> {
+        if resident == T::default() {
+            return Some(arriving);
+        }
+        self.encounters
+            .iter()
+            .find(|e| e.arriving == arriving && e.resident == resident)
+            .map(|e| e.result)
+    }
+}
+
+impl ReactionTable<Occupancy> {
+    /// Annihilation $A + A \to 0$ with a given rate
+    pub fn annihilation(rate: f64) -> Self {
+        Self::new().with_pair_reaction(
+            [Occupancy::Occupied, Occupancy::Occupied],
+            [Occupancy::Empty, Occupancy::Empty],
+            rate,
+        )
+    }
+
+    /// Coagulation $A + A \to A$ with a given rate, the particle merging into its neighbor
+    pub fn coagulation(rate: f64) -> Self {
+        Self::new().with_pair_reaction(
+            [Occupancy::Occupied, Occupancy::Occupied],
+            [Occupancy::Empty, Occupancy::Occupied],
+            rate,
+        )
+    }
+
+    /// Branching-annihilating walk $A \to (1 + n) A$, $A + A \to 0$
+    /// Offspring landing on an occupied site annihilate with its particle.
+    pub fn branching_annihilating(
+        offspring: usize,
+        branching_rate: f64,
+        annihilation_rate: f64,
+    ) -> Self {
+        Self::annihilation(annihilation_rate)
+            .with_branching(Occupancy::Occupied, offspring, branching_rate)
+            .with_encounter(Occupancy::Occupied, Occupancy::Occupied, Occupancy::Empty)
+    }
+}
+
+impl ReactionTable<Particle> {
+    /// Two-species annihilation $A + B \to 0$ with a given rate
+    pub fn two_species_annihilation(rate: f64) -> Self {
+        Self::new().with_pair_reaction(
+            [Particle::A, Particle::B],
+            [Particle::Empty, Particle::Empty],
+            rate,
+        )
+    }
+}
+
+/// Reaction–diffusion process
+/// Each unit of time:
+/// - Every particle attempts each reaction of the table with its rate
+/// - A swap diffusion sweep moves the particles
+///
+/// Reaction events are drawn uniformly over the particles with all reaction rates summed,
+/// each advancing time by $1 / (R N)$, where $R$ is the total rate and $N$ the number of particles.
+/// As in the other processes, the last event of a step may overshoot the unit of time
+/// and the overshoot is carried into the next step.
+/// Steps without reaction events still advance time by the unit of the diffusion sweep.
+#[derive(Debug, Clone)]
+pub struct ReactionDiffusion<S>
+where
+    S: SiteStateNN + SimpleSwapDiffusion,
+{
+    /// Current state
+    state: S,
+    /// Reaction table
+    table: ReactionTable<S::Site>,
+    /// Swap coin of the diffusion sweep
+    diffusion_coin: Bernoulli,
+    /// Occupied sites
+    active: ActiveSites,
+    /// Elapsed time
+    time: f64,
+}
+
+impl<S> ReactionDiffusion<S>
+where
+    S: SiteStateNN + SimpleSwapDiffusion,
+    S::Site: PartialEq + Default,
+{
+    /// Create new process from an initial state, reaction table and diffusion coin
+    pub fn new(state: S, table: ReactionTable<S::Site>, diffusion_coin: Bernoulli) -> Self {
+        let active = ActiveSites::from_state(&state, |s| *s != S::Site::default());
+        Self {
+            state,
+            table,
+            diffusion_coin,
+            active,
+            time: 0.0,
+        }
+    }
+
+    /// Reaction table
+    #[inline(always)]
+    pub fn table(&self) -> &ReactionTable<S::Site> {
+        &self.table
+    }
+
+    /// Occupied sites
+    #[inline(always)]
+    pub fn active_sites(&self) -> &ActiveSites {
+        &self.active
+    }
+
+    /// Set a site keeping the occupied sites up to date
+    fn set_site(&mut self, idx: S::Index, site: S::Site) {
+        self.state[idx] = site;
+        let k = self.state.linear_index(idx);
+        if site != S::Site::default() {
+            self.active.insert(k);
+        } else {
+            self.active.remove(k);
+        }
+    }
+
+    /// Attempt the reaction selected by `u` in `0..total_rate` on a given particle
+    fn react<R: Rng + ?Sized>(&mut self, k: usize, mut u: f64, rng: &mut R) {
+        let idx = self.state.site_index(k);
+        let site = self.state[idx];
+        // Conversions
+        for i in 0..self.table.conversions.len() {
+            let reaction = self.table.conversions[i];
+            if u < reaction.rate {
+                if site == reaction.from {
+                    self.set_site(idx, reaction.into);
+                }
+                return;
+            }
+            u -= reaction.rate;
+        }
+        // Pair reactions
+        for i in 0..self.table.pair_reactions.len() {
+            let reaction = self.table.pair_reactions[i];
+            if u < reaction.rate {
+                let Some(nn_idx) = self.state.nearest_neighbors_index(idx).choose(rng) else {
+                    return;
+                };
+                let pair = [site, self.state[nn_idx]];
+                let [a, b] = reaction.products;
+                if pair == reaction.reactants {
+                    self.set_site(idx, a);
+                    self.set_site(nn_idx, b);
+                } else if pair == [reaction.reactants[1], reaction.reactants[0]] {
+                    self.set_site(idx, b);
+                    self.set_site(nn_idx, a);
+                }
+                return;
+            }
+            u -= reaction.rate;
+        }
+        // Branchings
+        for i in 0..self.table.branchings.len() {
+            let reaction = self.table.branchings[i];
+            if u < reaction.rate {
+                if site == reaction.parent {
+                    let targets = self
+                        .state
+                        .nearest_neighbors_index(idx)
+                        .choose_multiple(rng, reaction.offspring);
+                    for nn_idx in targets {
+                        if let Some(result) = self.table.arrive(site, self.state[nn_idx]) {
+                            self.set_site(nn_idx, result);
+                        }
+                    }
+                }
+                return;
+            }
+            u -= reaction.rate;
+        }
+    }
+
+    /// Record the particle density after each of `max_time` steps, starting at $t = 0$
+    pub fn density_decay<R: Rng + ?Sized>(&mut self, max_time: usize, rng: &mut R) -> Vec<f64> {
+        let site_count = self.state.site_count() as f64;
+        let mut density = vec![self.active.len() as f64 / site_count];
+        for _ in 0..max_time {
+            self.step(rng);
+            density.push(self.active.len() as f64 / site_count);
+        }
+        density
+    }
+}
+
+impl<S> StochasticProcess for ReactionDiffusion<S>
+where
+    S: SiteStateNN + SimpleSwapDiffusion,
+    S::Site: PartialEq + Default,
+{
+    type State = S;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+        self.active
+            .rebuild(&self.state, |s| *s != S::Site::default());
+    }
+
+    fn reset<I: InitialStateSpec<S>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.active
+            .rebuild(&self.state, |s| *s != S::Site::default());
+        self.time = 0.0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let end = self.time + 1.0;
+        let total_rate = self.table.total_rate();
+        // Reaction events
+        if total_rate > 0.0 {
+            while self.time < end {
+                let Some(k) = self.active.choose(rng) else {
+                    break;
+                };
+                self.time += 1.0 / (total_rate * self.active.len() as f64);
+                let u = rng.gen_range(0.0..total_rate);
+                self.react(k, u, rng);
+            }
+        }
+        self.time = self.time.max(end);
+        // Diffusion sweep
+        self.state.diffuse(self.diffusion_coin, rng);
+        self.active
+            .rebuild(&self.state, |s| *s != S::Site::default());
+    }
+}
+
+impl<S> AbsorbingProcess for ReactionDiffusion<S>
+where
+    S: SiteStateNN + SimpleSwapDiffusion,
+    S::Site: PartialEq + Default,
+{
+    #[inline(always)]
+    fn active_count(&self) -> usize {
+        self.active.len()
+    }
+
+    #[inline(always)]
+    fn active_indices(&self) -> impl Iterator<Item = usize> {
+        self.active.iter()
+    }
+}
+
+/// Effective decay exponents $\delta(t) = -\ln[\rho(t) / \rho(t / m)] / \ln m$
+/// of a density series indexed by time $t = 0, 1, \ldots$
+///
+/// Entry $i$ corresponds to $t = m (i + 1)$, vanishing densities give `NaN`.
+pub fn effective_exponents(density: &[f64], m: usize) -> Vec<f64> {
+    let ln_m = (m as f64).ln();
+    (m..density.len())
+        .step_by(m)
+        .map(|t| -(density[t] / density[t / m]).ln() / ln_m)
+        .collect()
+}
+
+/// Density of a given species
+#[derive(Debug, Clone, Copy)]
+pub struct SpeciesDensity<T>(pub T);
+
+impl<S, T> StateMeasurement<S> for SpeciesDensity<T>
+where
+    S: SiteState<Site = T>,
+    T: Clone + Copy + PartialEq,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        state.sites().filter(|&&s| s == self.0).count() as f64 / state.site_count() as f64
+    }
+}
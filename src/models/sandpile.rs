@@ -0,0 +1,243 @@
+//! Sandpile Models
+//!
+//! Self-organized criticality models with integer heights on each site,
+//! driven by adding single grains and relaxed through avalanches of topplings.
+//! Grains sent across the open boundaries of the state are dissipated.
+
+use crate::states::{OpenBoundaries, SiteStateNN};
+use rand::{Rng, seq::IteratorRandom};
+
+/// Toppling rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandpileRule {
+    /// Bak–Tang–Wiesenfeld: sites with $h \geq z$ send one grain to each of their $z$ nearest neighbors
+    Btw,
+    /// Manna: sites with $h \geq 2$ send two grains to independently chosen random nearest neighbors
+    Manna,
+    /// Oslo: sites with $h \geq z^c$ topple as in the BTW model,
+    /// with thresholds $z^c \in \{z, z + 1\}$ redrawn uniformly after each toppling
+    Oslo,
+}
+
+/// Single avalanche
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Avalanche {
+    /// Number of topplings
+    pub size: usize,
+    /// Number of parallel toppling generations
+    pub duration: usize,
+    /// Number of distinct sites that toppled
+    pub area: usize,
+    /// Number of grains dissipated through the boundaries
+    pub dissipated: usize,
+}
+
+/// Avalanches recorded for each grain addition
+#[derive(Debug, Clone, Default)]
+pub struct AvalancheRecord {
+    /// Avalanche sizes
+    pub sizes: Vec<usize>,
+    /// Avalanche durations
+    pub durations: Vec<usize>,
+    /// Avalanche areas
+    pub areas: Vec<usize>,
+}
+
+impl AvalancheRecord {
+    /// Append an avalanche
+    #[inline(always)]
+    pub fn record(&mut self, avalanche: &Avalanche) {
+        self.sizes.push(avalanche.size);
+        self.durations.push(avalanche.duration);
+        self.areas.push(avalanche.area);
+    }
+
+    /// Number of recorded avalanches
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Check if no avalanche was recorded
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+}
+
+/// Sandpile with open boundaries
+#[derive(Debug, Clone)]
+pub struct Sandpile<S>
+where
+    S: SiteStateNN<Site = u32> + OpenBoundaries,
+{
+    /// Current heights
+    state: S,
+    /// Toppling rule
+    rule: SandpileRule,
+    /// Toppling threshold of each site
+    thresholds: Vec<u32>,
+    /// Whether each site toppled in the current avalanche
+    toppled: Vec<bool>,
+}
+
+impl<S> Sandpile<S>
+where
+    S: SiteStateNN<Site = u32> + OpenBoundaries,
+{
+    /// Create new sandpile from initial heights and toppling rule
+    ///
+    /// Oslo thresholds start at their lower value $z$.
+    pub fn new(state: S, rule: SandpileRule) -> Self {
+        let thresholds = state
+            .indices()
+            .map(|idx| match rule {
+                SandpileRule::Manna => 2,
+                _ => state.open_neighbors_index(idx).count() as u32,
+            })
+            .collect();
+        Self {
+            toppled: vec![false; state.site_count()],
+            state,
+            rule,
+            thresholds,
+        }
+    }
+
+    /// Current heights
+    #[inline(always)]
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Toppling rule
+    #[inline(always)]
+    pub fn rule(&self) -> SandpileRule {
+        self.rule
+    }
+
+    /// Total number of grains
+    pub fn mass(&self) -> u64 {
+        self.state.sites().map(|&h| h as u64).sum()
+    }
+
+    /// Add a grain to a random site and relax
+    pub fn drive<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Avalanche {
+        let idx = self.state.sample(rng);
+        self.add_grain(idx, rng)
+    }
+
+    /// Add a grain to a given site and relax the avalanche it triggers
+    pub fn add_grain<R: Rng + ?Sized>(&mut self, idx: S::Index, rng: &mut R) -> Avalanche {
+        self.state[idx] += 1;
+        let k = self.state.linear_index(idx);
+        match self.is_unstable(k) {
+            true => self.topple(vec![k], rng),
+            false => Avalanche::default(),
+        }
+    }
+
+    /// Drive with `grains` random grain additions recording every avalanche
+    pub fn run<R: Rng + ?Sized>(&mut self, grains: usize, rng: &mut R) -> AvalancheRecord {
+        let mut record = AvalancheRecord::default();
+        for _ in 0..grains {
+            record.record(&self.drive(rng));
+        }
+        record
+    }
+
+    /// Check if a site is unstable
+    #[inline(always)]
+    fn is_unstable(&self, k: usize) -> bool {
+        self.state[self.state.site_index(k)] >= self.thresholds[k]
+    }
+
+    /// Send a grain to a neighbor, `None` past the boundary, returns whether it was dissipated
+    #[inline(always)]
+    fn send_grain(&mut self, nn_idx: Option<S::Index>, unstable: &mut Vec<usize>) -> bool {
+        let Some(nn_idx) = nn_idx else {
+            return true;
+        };
+        self.state[nn_idx] += 1;
+        let nn_k = self.state.linear_index(nn_idx);
+        if self.is_unstable(nn_k) {
+            unstable.push(nn_k);
+        }
+        false
+    }
+
+    /// Relax all unstable sites of an arbitrary state in parallel generations
+    pub fn relax<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Avalanche {
+        let unstable = (0..self.state.site_count())
+            .filter(|&k| self.is_unstable(k))
+            .collect();
+        self.topple(unstable, rng)
+    }
+
+    /// Topple in parallel generations starting from given unstable sites
+    fn topple<R: Rng + ?Sized>(&mut self, mut current: Vec<usize>, rng: &mut R) -> Avalanche {
+        let mut avalanche = Avalanche::default();
+        let mut next = Vec::new();
+        let mut visited = Vec::new();
+        while !current.is_empty() {
+            let size = avalanche.size;
+            for &k in &current {
+                // Sites may appear repeatedly or already be relaxed
+                if !self.is_unstable(k) {
+                    continue;
+                }
+                avalanche.size += 1;
+                if !self.toppled[k] {
+                    self.toppled[k] = true;
+                    visited.push(k);
+                }
+                let idx = self.state.site_index(k);
+                match self.rule {
+                    SandpileRule::Btw | SandpileRule::Oslo => {
+                        let neighbors: Vec<Option<S::Index>> =
+                            self.state.open_neighbors_index(idx).collect();
+                        self.state[idx] -= neighbors.len() as u32;
+                        for nn_idx in neighbors {
+                            avalanche.dissipated += self.send_grain(nn_idx, &mut next) as usize;
+                        }
+                        if self.rule == SandpileRule::Oslo {
+                            self.thresholds[k] = oslo_threshold(&self.state, idx, rng);
+                        }
+                    }
+                    SandpileRule::Manna => {
+                        self.state[idx] -= 2;
+                        for _ in 0..2 {
+                            if let Some(nn_idx) = self.state.open_neighbors_index(idx).choose(rng) {
+                                avalanche.dissipated += self.send_grain(nn_idx, &mut next) as usize;
+                            }
+                        }
+                    }
+                }
+                if self.is_unstable(k) {
+                    next.push(k);
+                }
+            }
+            // Generations whose sites were all relaxed already do not count
+            if avalanche.size > size {
+                avalanche.duration += 1;
+            }
+            std::mem::swap(&mut current, &mut next);
+            next.clear();
+        }
+        avalanche.area = visited.len();
+        for k in visited {
+            self.toppled[k] = false;
+        }
+        avalanche
+    }
+}
+
+/// Random Oslo threshold $z^c \in \{z, z + 1\}$ of a site with $z$ nearest neighbors
+#[inline(always)]
+fn oslo_threshold<S, R>(state: &S, idx: S::Index, rng: &mut R) -> u32
+where
+    S: OpenBoundaries,
+    R: Rng + ?Sized,
+{
+    state.open_neighbors_index(idx).count() as u32 + rng.gen_range(0..2)
+}
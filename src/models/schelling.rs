@@ -0,0 +1,300 @@
+//! Schelling Segregation Model
+//!
+//! Agents of several types living on the sites of a state relocate to vacancies
+//! when too few of their neighbors share their type, leading to segregated domains
+//! even for tolerant agents.
+//!
+//! Schelling, J. Math. Sociol. 1, 143 (1971)
+
+use super::MonteCarloUpdate;
+use crate::states::{
+    SiteCharRepr, SiteStateNN, StateMeasurement, active_sites::ActiveSites, clusters::Clusters,
+};
+use rand::{Rng, seq::SliceRandom};
+use rand_distr::Distribution;
+use std::collections::VecDeque;
+
+/// Schelling site, vacant or housing an agent of one of the types `0..K`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Dwelling {
+    #[default]
+    Vacant,
+    Agent(u8),
+}
+
+impl SiteCharRepr for Dwelling {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            Dwelling::Vacant => ' ',
+            Dwelling::Agent(t) => char::from_digit(*t as u32, 36).unwrap_or('?'),
+        }
+    }
+}
+
+/// Random population with a given vacancy density and agent types drawn uniformly from `0..K`
+#[derive(Debug, Clone, Copy)]
+pub struct Population {
+    /// Probability of a vacant site
+    pub vacancy: f64,
+    /// Number of agent types $K$
+    pub types: u8,
+}
+
+impl Distribution<Dwelling> for Population {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Dwelling {
+        match rng.r#gen::<f64>() < self.vacancy {
+            true => Dwelling::Vacant,
+            false => Dwelling::Agent(rng.gen_range(0..self.types)),
+        }
+    }
+}
+
+/// Choice of the vacancy an unhappy agent relocates to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Relocation {
+    /// Uniformly random vacancy
+    RandomVacancy,
+    /// Uniformly random vacancy where the agent would be satisfied
+    #[default]
+    RandomSatisfying,
+    /// Vacancy where the agent would be satisfied closest along nearest neighbors links,
+    /// ties broken randomly
+    NearestSatisfying,
+}
+
+/// Number of nearest neighbors of a site sharing a given agent type and number of occupied ones
+fn like_neighbors<S>(state: &S, idx: S::Index, agent: u8) -> (usize, usize)
+where
+    S: SiteStateNN<Site = Dwelling>,
+{
+    state
+        .nearest_neighbors(idx)
+        .fold((0, 0), |(like, occupied), s| match s {
+            Dwelling::Agent(t) => (like + (*t == agent) as usize, occupied + 1),
+            Dwelling::Vacant => (like, occupied),
+        })
+}
+
+/// Schelling update
+/// An agent is satisfied if at least a fraction `threshold` of its occupied nearest neighbors
+/// share its type, agents without occupied neighbors being satisfied.
+/// Each Monte Carlo step visits $N$ random sites, relocating the unhappy agents.
+/// Agents stay in place if no suitable vacancy exists.
+#[derive(Debug, Clone, Copy)]
+pub struct Schelling {
+    /// Satisfaction threshold on the fraction of like neighbors
+    pub threshold: f64,
+    /// Relocation rule
+    pub relocation: Relocation,
+}
+
+impl Schelling {
+    /// Create update with a given satisfaction threshold and relocation rule
+    pub fn new(threshold: f64, relocation: Relocation) -> Self {
+        Self {
+            threshold,
+            relocation,
+        }
+    }
+
+    /// Check if an agent of a given type would be satisfied at a given site
+    pub fn is_satisfied<S>(&self, state: &S, idx: S::Index, agent: u8) -> bool
+    where
+        S: SiteStateNN<Site = Dwelling>,
+    {
+        let (like, occupied) = like_neighbors(state, idx, agent);
+        occupied == 0 || like as f64 >= self.threshold * occupied as f64
+    }
+
+    /// Find a vacancy for the agent at a given site, which must be vacated beforehand
+    fn find_vacancy<S, R>(
+        &self,
+        state: &S,
+        idx: S::Index,
+        agent: u8,
+        vacancies: &ActiveSites,
+        rng: &mut R,
+    ) -> Option<usize>
+    where
+        S: SiteStateNN<Site = Dwelling>,
+        R: Rng + ?Sized,
+    {
+        match self.relocation {
+            Relocation::RandomVacancy => vacancies.choose(rng),
+            Relocation::RandomSatisfying => {
+                let satisfying: Vec<usize> = vacancies
+                    .iter()
+                    .filter(|&k| self.is_satisfied(state, state.site_index(k), agent))
+                    .collect();
+                satisfying.choose(rng).copied()
+            }
+            Relocation::NearestSatisfying => {
+                // Breadth-first search over shells of increasing distance
+                let mut visited = vec![false; state.site_count()];
+                visited[state.linear_index(idx)] = true;
+                let mut front = VecDeque::from([idx]);
+                while !front.is_empty() {
+                    let mut shell = Vec::new();
+                    for _ in 0..front.len() {
+                        let idx = front.pop_front().expect("Front must not be empty");
+                        for nn_idx in state.nearest_neighbors_index(idx) {
+                            let k = state.linear_index(nn_idx);
+                            if !visited[k] {
+                                visited[k] = true;
+                                shell.push(nn_idx);
+                                front.push_back(nn_idx);
+                            }
+                        }
+                    }
+                    let satisfying: Vec<usize> = shell
+                        .into_iter()
+                        .filter(|&nn_idx| {
+                            state[nn_idx] == Dwelling::Vacant
+                                && self.is_satisfied(state, nn_idx, agent)
+                        })
+                        .map(|nn_idx| state.linear_index(nn_idx))
+                        .collect();
+                    if let Some(&k) = satisfying.choose(rng) {
+                        return Some(k);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<S> MonteCarloUpdate<S> for Schelling
+where
+    S: SiteStateNN<Site = Dwelling>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        let mut vacancies = ActiveSites::from_state(state, |&s| s == Dwelling::Vacant);
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            let Dwelling::Agent(agent) = state[idx] else {
+                continue;
+            };
+            if self.is_satisfied(state, idx, agent) {
+                continue;
+            }
+            // Vacate the site while searching so that the agent does not count itself
+            state[idx] = Dwelling::Vacant;
+            match self.find_vacancy(state, idx, agent, &vacancies, rng) {
+                Some(k) => {
+                    let target = state.site_index(k);
+                    state[target] = Dwelling::Agent(agent);
+                    vacancies.remove(k);
+                    vacancies.insert(state.linear_index(idx));
+                }
+                None => state[idx] = Dwelling::Agent(agent),
+            }
+        }
+    }
+}
+
+/// Fraction of agents who are not satisfied
+#[derive(Debug, Clone, Copy)]
+pub struct UnhappyFraction {
+    /// Satisfaction threshold on the fraction of like neighbors
+    pub threshold: f64,
+}
+
+impl<S> StateMeasurement<S> for UnhappyFraction
+where
+    S: SiteStateNN<Site = Dwelling>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let schelling = Schelling::new(self.threshold, Relocation::default());
+        let (mut unhappy, mut agents) = (0, 0);
+        for idx in state.indices() {
+            if let Dwelling::Agent(agent) = state[idx] {
+                agents += 1;
+                unhappy += !schelling.is_satisfied(state, idx, agent) as usize;
+            }
+        }
+        if agents > 0 {
+            unhappy as f64 / agents as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Segregation index
+/// Mean fraction of like neighbors among the occupied nearest neighbors of each agent,
+/// over agents with at least one occupied neighbor
+#[derive(Debug, Clone, Copy)]
+pub struct SegregationIndex;
+
+impl<S> StateMeasurement<S> for SegregationIndex
+where
+    S: SiteStateNN<Site = Dwelling>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let (mut sum, mut agents) = (0.0, 0);
+        for idx in state.indices() {
+            let Dwelling::Agent(agent) = state[idx] else {
+                continue;
+            };
+            let (like, occupied) = like_neighbors(state, idx, agent);
+            if occupied > 0 {
+                sum += like as f64 / occupied as f64;
+                agents += 1;
+            }
+        }
+        if agents > 0 { sum / agents as f64 } else { 0.0 }
+    }
+}
+
+/// Statistics of the clusters of nearest neighbor agents sharing the same type
+#[derive(Debug, Clone, Copy)]
+pub struct ClusterStats {
+    /// Number of clusters
+    pub count: usize,
+    /// Size of the largest cluster
+    pub largest: usize,
+    /// Mean cluster size
+    pub mean_size: f64,
+}
+
+/// Cluster statistics of like agents, vacancies excluded
+#[derive(Debug, Clone, Copy)]
+pub struct AgentClusters;
+
+impl<S> StateMeasurement<S> for AgentClusters
+where
+    S: SiteStateNN<Site = Dwelling>,
+{
+    type Type = ClusterStats;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let clusters = Clusters::identify(state, |a, b| *a != Dwelling::Vacant && a == b);
+        let mut is_agent_cluster = vec![false; clusters.count()];
+        for (&label, &s) in clusters.labels.iter().zip(state.sites()) {
+            if s != Dwelling::Vacant {
+                is_agent_cluster[label] = true;
+            }
+        }
+        let sizes: Vec<usize> = clusters
+            .sizes
+            .iter()
+            .zip(&is_agent_cluster)
+            .filter_map(|(&size, &agent)| agent.then_some(size))
+            .collect();
+        ClusterStats {
+            count: sizes.len(),
+            largest: sizes.iter().copied().max().unwrap_or(0),
+            mean_size: match sizes.len() {
+                0 => 0.0,
+                n => sizes.iter().sum::<usize>() as f64 / n as f64,
+            },
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Space-Time Recording
+//!
+
+use super::StochasticProcess;
+use crate::states::SiteState;
+use rand::Rng;
+use std::fmt::Display;
+
+/// Space-time diagram recording successive states of a system
+#[derive(Debug, Clone)]
+pub struct SpaceTime<S: SiteState + Clone> {
+    /// Recorded states in chronological order
+    frames: Vec<S>,
+}
+
+impl<S: SiteState + Clone> Default for SpaceTime<S> {
+    fn default() -> Self {
+        Self { frames: Vec::new() }
+    }
+}
+
+impl<S: SiteState + Clone> SpaceTime<S> {
+    /// Create empty diagram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a copy of a state
+    #[inline(always)]
+    pub fn record(&mut self, state: &S) {
+        self.frames.push(state.clone());
+    }
+
+    /// Number of recorded states
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Check if no state was recorded
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Recorded states in chronological order
+    #[inline(always)]
+    pub fn frames(&self) -> &[S] {
+        &self.frames
+    }
+
+    /// Record the current state of a process and its state after each of `steps` unit time steps
+    pub fn run<P, R>(&mut self, process: &mut P, steps: usize, rng: &mut R)
+    where
+        P: StochasticProcess<State = S>,
+        R: Rng + ?Sized,
+    {
+        self.record(process.state());
+        for _ in 0..steps {
+            process.step(rng);
+            self.record(process.state());
+        }
+    }
+
+    /// Discard all recorded states
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+}
+
+/// Display each recorded state on its own line, time running downwards
+impl<S> Display for SpaceTime<S>
+where
+    S: SiteState + Clone + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for frame in &self.frames {
+            writeln!(f, "{frame}")?;
+        }
+        Ok(())
+    }
+}
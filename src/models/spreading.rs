@@ -0,0 +1,82 @@
+//! Spreading Experiments
+//!
+
+use super::AbsorbingProcess;
+use crate::states::{InitialStateSpec, SiteState, SiteStateMetric};
+use rand::Rng;
+
+/// Averages of spreading experiments indexed by time $t = 0, 1, \ldots$
+#[derive(Debug, Clone)]
+pub struct SpreadingStats {
+    /// Number of trials
+    pub trials: usize,
+    /// Survival probability $P(t)$
+    pub survival: Vec<f64>,
+    /// Mean number of active sites $N(t)$ over all trials
+    pub mean_active: Vec<f64>,
+    /// Mean-square spread $R^2(t) = \langle \sum_i r_i^2 \rangle / \langle N(t) \rangle$
+    pub mean_squared_spread: Vec<f64>,
+}
+
+/// Spreading experiment
+/// Repeatedly reset a process to an initial state with a localized seed
+/// and follow its evolution until absorption or a maximum time.
+///
+/// Distances are measured from the first initially active site,
+/// which for a single seed is the seed itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Spreading {
+    /// Number of independent trials
+    pub trials: usize,
+    /// Maximum time of each trial
+    pub max_time: usize,
+}
+
+impl Spreading {
+    /// Run all trials on a given process reset with a given seed specification
+    pub fn run<P, I, R>(&self, process: &mut P, spec: &mut I, rng: &mut R) -> SpreadingStats
+    where
+        P: AbsorbingProcess,
+        P::State: SiteStateMetric,
+        I: InitialStateSpec<P::State>,
+        R: Rng + ?Sized,
+    {
+        let len = self.max_time + 1;
+        let mut surviving = vec![0usize; len];
+        let mut active = vec![0usize; len];
+        let mut spread = vec![0.0; len];
+        for _ in 0..self.trials {
+            process.reset(spec);
+            let Some(origin) = process.active_indices().next() else {
+                continue;
+            };
+            let origin = process.state().site_index(origin);
+            for t in 0..len {
+                if t > 0 {
+                    process.step(rng);
+                }
+                if process.is_absorbed() {
+                    break;
+                }
+                let state = process.state();
+                surviving[t] += 1;
+                active[t] += process.active_count();
+                spread[t] += process
+                    .active_indices()
+                    .map(|k| state.squared_distance(origin, state.site_index(k)))
+                    .sum::<f64>();
+            }
+        }
+        let trials = self.trials as f64;
+        SpreadingStats {
+            trials: self.trials,
+            survival: surviving.iter().map(|&n| n as f64 / trials).collect(),
+            mean_active: active.iter().map(|&n| n as f64 / trials).collect(),
+            mean_squared_spread: spread
+                .iter()
+                .zip(&active)
+                .map(|(&r2, &n)| if n > 0 { r2 / n as f64 } else { 0.0 })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Swendsen–Wang Multi-Cluster Algorithm
+//!
+
+use super::{MonteCarloUpdate, PARALLEL_CHUNK_SIZE, chunk_seeds};
+use crate::states::{
+    SiteStateNN,
+    clusters::{Clusters, UnionFind},
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rand_distr::{Bernoulli, Distribution};
+use rayon::prelude::*;
+
+/// Swendsen–Wang update
+/// - Activate bonds between equal nearest neighbors with a given probability
+/// - Label the resulting clusters with a union-find
+/// - Assign each cluster a new site state drawn independently from a given distribution
+#[derive(Debug, Clone)]
+pub struct SwendsenWang<D> {
+    /// Bond activation coin
+    bond_coin: Bernoulli,
+    /// Distribution of the new state of each cluster
+    cluster_dist: D,
+}
+
+impl<D> SwendsenWang<D> {
+    /// Create new update with given bond activation coin and cluster state distribution
+    pub fn new(bond_coin: Bernoulli, cluster_dist: D) -> Self {
+        Self {
+            bond_coin,
+            cluster_dist,
+        }
+    }
+
+    /// Update for the Ising model at inverse temperature `beta` with coupling `coupling`
+    ///
+    /// Bond probability $p = 1 - e^{-2 \beta J}$.
+    ///
+    /// Panics if `beta * coupling` is negative.
+    pub fn ising(beta: f64, coupling: f64, cluster_dist: D) -> Self {
+        let bond_coin = Bernoulli::new(1.0 - f64::exp(-2.0 * beta * coupling))
+            .expect("Ising bond probability must lie in [0, 1]");
+        Self::new(bond_coin, cluster_dist)
+    }
+
+    /// Perform a single update and return the clusters it flipped
+    ///
+    /// The returned clusters expose their sizes for improved estimators.
+    pub fn update<S, R>(&self, state: &mut S, rng: &mut R) -> Clusters
+    where
+        S: SiteStateNN,
+        S::Site: PartialEq,
+        D: Distribution<S::Site>,
+        R: Rng + ?Sized,
+    {
+        let clusters = self.activate_bonds(state, |_| true, rng).clusters();
+        // Draw a new state for each cluster
+        let cluster_sites: Vec<S::Site> = (&self.cluster_dist)
+            .sample_iter(&mut *rng)
+            .take(clusters.count())
+            .collect();
+        for (k, &label) in clusters.labels.iter().enumerate() {
+            let idx = state.site_index(k);
+            state[idx] = cluster_sites[label];
+        }
+        clusters
+    }
+
+    /// Activate bonds between equal nearest neighbors satisfying `active`
+    ///
+    /// Candidate bonds are collected sequentially and activated in parallel,
+    /// each chunk with its own generator seeded from `rng`.
+    pub(crate) fn activate_bonds<S, F, R>(&self, state: &S, active: F, rng: &mut R) -> UnionFind
+    where
+        S: SiteStateNN,
+        S::Site: PartialEq,
+        F: Fn(&S::Site) -> bool,
+        R: Rng + ?Sized,
+    {
+        let candidates: Vec<(usize, usize)> = state
+            .nearest_neighbors_index_pairs()
+            .filter(|&(a, b)| state[a] == state[b] && active(&state[a]))
+            .map(|(a, b)| (state.linear_index(a), state.linear_index(b)))
+            .collect();
+        let seeds = chunk_seeds(candidates.len(), rng);
+        let bond_coin = self.bond_coin;
+        let bonds: Vec<(usize, usize)> = candidates
+            .par_chunks(PARALLEL_CHUNK_SIZE)
+            .zip(seeds.par_iter())
+            .flat_map_iter(|(chunk, &seed)| {
+                let mut chunk_rng = StdRng::seed_from_u64(seed);
+                chunk
+                    .iter()
+                    .copied()
+                    .filter(move |_| bond_coin.sample(&mut chunk_rng))
+            })
+            .collect();
+        // Label clusters
+        let mut forest = UnionFind::new(state.site_count());
+        for (a, b) in bonds {
+            forest.union(a, b);
+        }
+        forest
+    }
+}
+
+impl<S, D> MonteCarloUpdate<S> for SwendsenWang<D>
+where
+    S: SiteStateNN,
+    S::Site: PartialEq,
+    D: Distribution<S::Site>,
+{
+    #[inline(always)]
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        self.update(state, rng);
+    }
+}
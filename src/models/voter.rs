@@ -0,0 +1,188 @@
+//! Voter Model and Variants
+//!
+
+use super::MonteCarloUpdate;
+use crate::states::{SiteState, SiteStateNN, StateMeasurement, sites::IsingSpin};
+use rand::{Rng, seq::IteratorRandom, seq::SliceRandom};
+use rand_distr::{Bernoulli, Distribution};
+
+/// Classic voter model
+/// Each visited site copies the opinion of a random nearest neighbor.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Voter;
+
+impl<S: SiteStateNN> MonteCarloUpdate<S> for Voter {
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            if let Some(&opinion) = state.nearest_neighbors(idx).choose(rng) {
+                state[idx] = opinion;
+            }
+        }
+    }
+}
+
+/// Noisy voter model
+/// Each visited site adopts an opinion drawn from a given distribution with the noise coin probability
+/// and otherwise copies the opinion of a random nearest neighbor.
+#[derive(Debug, Clone)]
+pub struct NoisyVoter<D> {
+    /// Spontaneous opinion change coin
+    pub noise_coin: Bernoulli,
+    /// Distribution of spontaneously adopted opinions
+    pub opinion_dist: D,
+}
+
+impl<S, D> MonteCarloUpdate<S> for NoisyVoter<D>
+where
+    S: SiteStateNN,
+    D: Distribution<S::Site>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            if self.noise_coin.sample(rng) {
+                state[idx] = self.opinion_dist.sample(rng);
+            } else if let Some(&opinion) = state.nearest_neighbors(idx).choose(rng) {
+                state[idx] = opinion;
+            }
+        }
+    }
+}
+
+/// $q$-voter model
+/// Each visited site consults a panel of $q$ nearest neighbors drawn with repetition:
+/// - A unanimous panel imposes its opinion
+/// - Otherwise the site adopts an opinion drawn from a given distribution with the noise coin probability
+#[derive(Debug, Clone)]
+pub struct QVoter<D> {
+    /// Panel size $q$
+    pub q: usize,
+    /// Spontaneous opinion change coin for non-unanimous panels
+    pub noise_coin: Bernoulli,
+    /// Distribution of spontaneously adopted opinions
+    pub opinion_dist: D,
+}
+
+impl<S, D> MonteCarloUpdate<S> for QVoter<D>
+where
+    S: SiteStateNN,
+    S::Site: PartialEq,
+    D: Distribution<S::Site>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        let mut neighbors = Vec::new();
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            neighbors.clear();
+            neighbors.extend(state.nearest_neighbors(idx).copied());
+            let Some(&first) = neighbors.choose(rng) else {
+                continue;
+            };
+            let unanimous = (1..self.q).all(|_| neighbors.choose(rng) == Some(&first));
+            if unanimous {
+                state[idx] = first;
+            } else if self.noise_coin.sample(rng) {
+                state[idx] = self.opinion_dist.sample(rng);
+            }
+        }
+    }
+}
+
+/// Majority-vote model
+/// Each visited spin aligns with the majority of its nearest neighbors,
+/// or against it with the noise coin probability.
+/// Ties are resolved with a uniformly random spin.
+#[derive(Debug, Clone, Copy)]
+pub struct MajorityVote {
+    /// Minority alignment coin
+    pub noise_coin: Bernoulli,
+}
+
+impl<S> MonteCarloUpdate<S> for MajorityVote
+where
+    S: SiteStateNN<Site = IsingSpin>,
+{
+    fn step<R: Rng + ?Sized>(&mut self, state: &mut S, rng: &mut R) {
+        for _ in 0..state.site_count() {
+            let idx = state.sample(rng);
+            let field: i32 = state.nearest_neighbors(idx).map(|s| s.value()).sum();
+            let majority = match field.signum() {
+                1 => IsingSpin::Up,
+                -1 => IsingSpin::Down,
+                _ => {
+                    state[idx] = rng.r#gen();
+                    continue;
+                }
+            };
+            state[idx] = match self.noise_coin.sample(rng) {
+                false => majority,
+                true => majority.flipped(),
+            };
+        }
+    }
+}
+
+/// Interface density: fraction of nearest neighbors pairs with different opinions
+#[derive(Debug, Clone, Copy)]
+pub struct InterfaceDensity;
+
+impl<S> StateMeasurement<S> for InterfaceDensity
+where
+    S: SiteStateNN,
+    S::Site: PartialEq,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let (active, total) = state
+            .nearest_neighbors_pairs()
+            .fold((0usize, 0usize), |(active, total), (a, b)| {
+                (active + (a != b) as usize, total + 1)
+            });
+        active as f64 / total as f64
+    }
+}
+
+/// Consensus: all sites share the same opinion
+#[derive(Debug, Clone, Copy)]
+pub struct Consensus;
+
+impl<S> StateMeasurement<S> for Consensus
+where
+    S: SiteState,
+    S::Site: PartialEq,
+{
+    type Type = bool;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let mut sites = state.sites();
+        match sites.next() {
+            Some(first) => sites.all(|s| s == first),
+            None => true,
+        }
+    }
+}
+
+/// Run an update until consensus is reached or for at most `max_steps` Monte Carlo steps
+/// Returns the consensus time in Monte Carlo steps, if reached
+pub fn run_until_consensus<S, U, R>(
+    update: &mut U,
+    state: &mut S,
+    max_steps: usize,
+    rng: &mut R,
+) -> Option<usize>
+where
+    S: SiteState,
+    S::Site: PartialEq,
+    U: MonteCarloUpdate<S>,
+    R: Rng + ?Sized,
+{
+    for t in 0..max_steps {
+        if Consensus.measure(state) {
+            return Some(t);
+        }
+        update.step(state, rng);
+    }
+    Consensus.measure(state).then_some(max_steps)
+}
@@ -0,0 +1,363 @@
+//! Zero-Range and Inclusion Processes
+//!
+//! Bosonic particles on the `u32` sites of a state hop between nearest neighbors
+//! with rates depending on the occupation numbers:
+//! - Zero-range process: rate $u(n_i)$, e.g. $u(n) = 1 + b / n$ condensing for $b > 2$
+//!   above the critical density $\rho_c = 1 / (b - 2)$
+//!
+//!   Evans & Hanney, J. Phys. A 38, R195 (2005)
+//! - Inclusion process: rate $n_i (d + n_j)$, condensing for $d \to 0$
+//!
+//!   Grosskinsky, Redig & Vafayi, J. Stat. Phys. 142, 952 (2011)
+
+use super::StochasticProcess;
+use crate::{
+    states::{InitialStateSpec, SiteState, SiteStateNN, StateMeasurement},
+    utils::histogram,
+};
+use rand::Rng;
+use rand_distr::{Distribution, Exp1};
+
+/// Hop rate of bosonic particles
+pub trait HopRate {
+    /// Rate at which a particle leaves a site holding `from` particles
+    /// towards a nearest neighbor holding `to` particles
+    fn rate(&self, from: u32, to: u32) -> f64;
+}
+
+/// Zero-range hop rate $u(n_i)$ depending only on the departure site
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroRange<U: Fn(u32) -> f64>(pub U);
+
+impl<U: Fn(u32) -> f64> HopRate for ZeroRange<U> {
+    #[inline(always)]
+    fn rate(&self, from: u32, _to: u32) -> f64 {
+        if from > 0 { (self.0)(from) } else { 0.0 }
+    }
+}
+
+/// Inclusion hop rate $n_i (d + n_j)$
+#[derive(Debug, Clone, Copy)]
+pub struct Inclusion {
+    /// Diffusion constant $d$
+    pub diffusion: f64,
+}
+
+impl HopRate for Inclusion {
+    #[inline(always)]
+    fn rate(&self, from: u32, to: u32) -> f64 {
+        from as f64 * (self.diffusion + to as f64)
+    }
+}
+
+/// Binary indexed tree of site rates for sampling sites proportionally to their rate
+#[derive(Debug, Clone)]
+struct RateTree {
+    /// Rate of each site
+    rates: Vec<f64>,
+    /// Partial sums
+    tree: Vec<f64>,
+}
+
+impl RateTree {
+    /// Build tree from the rates of all sites
+    fn new(rates: Vec<f64>) -> Self {
+        let mut tree = rates.clone();
+        for i in 1..=tree.len() {
+            let j = i + (i & i.wrapping_neg());
+            if j <= tree.len() {
+                tree[j - 1] += tree[i - 1];
+            }
+        }
+        Self { rates, tree }
+    }
+
+    /// Total rate
+    fn total(&self) -> f64 {
+        let mut i = self.tree.len();
+        let mut sum = 0.0;
+        while i > 0 {
+            sum += self.tree[i - 1];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// Set rate of site `k`
+    fn set(&mut self, k: usize, rate: f64) {
+        let delta = rate - self.rates[k];
+        self.rates[k] = rate;
+        let mut i = k + 1;
+        while i <= self.tree.len() {
+            self.tree[i - 1] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Site whose cumulative rate interval contains `u` in `0..total()`
+    fn find(&self, mut u: f64) -> usize {
+        let n = self.tree.len();
+        let mut pos = 0;
+        let mut step = match n {
+            0 => 0,
+            _ => 1 << (usize::BITS - 1 - n.leading_zeros()),
+        };
+        while step > 0 {
+            if pos + step <= n && self.tree[pos + step - 1] <= u {
+                pos += step;
+                u -= self.tree[pos - 1];
+            }
+            step >>= 1;
+        }
+        pos.min(n - 1)
+    }
+}
+
+/// Bosonic hopping process
+/// A particle leaves each site $i$ towards a nearest neighbor $j$ with rate $w(n_i, n_j)$ averaged
+/// over the neighbors, the neighbor being chosen proportionally to $w(n_i, n_j)$.
+///
+/// Events are performed in continuous time with sites sampled proportionally to their rate,
+/// each advancing time by an exponential waiting time with the total rate as parameter.
+/// Waiting times are memoryless, so the one reaching past the end of the unit of time is discarded.
+#[derive(Debug, Clone)]
+pub struct BosonicHopping<S, H>
+where
+    S: SiteStateNN<Site = u32>,
+    H: HopRate,
+{
+    /// Current state
+    state: S,
+    /// Hop rate $w(n_i, n_j)$
+    hop_rate: H,
+    /// Rate of each site
+    rates: RateTree,
+    /// Elapsed time
+    time: f64,
+}
+
+impl<S, H> BosonicHopping<S, H>
+where
+    S: SiteStateNN<Site = u32>,
+    H: HopRate,
+{
+    /// Create new process from an initial state and hop rate
+    pub fn new(state: S, hop_rate: H) -> Self {
+        let rates = RateTree::new(Vec::new());
+        let mut process = Self {
+            state,
+            hop_rate,
+            rates,
+            time: 0.0,
+        };
+        process.rebuild_rates();
+        process
+    }
+
+    /// Hop rate
+    #[inline(always)]
+    pub fn hop_rate(&self) -> &H {
+        &self.hop_rate
+    }
+
+    /// Total number of particles
+    pub fn particle_count(&self) -> u64 {
+        self.state.sites().map(|&n| n as u64).sum()
+    }
+
+    /// Rate of a particle leaving a given site
+    fn site_rate(&self, idx: S::Index) -> f64 {
+        let n = self.state[idx];
+        if n == 0 {
+            return 0.0;
+        }
+        let (sum, count) = self
+            .state
+            .nearest_neighbors_index(idx)
+            .fold((0.0, 0), |(sum, count), nn_idx| {
+                (sum + self.hop_rate.rate(n, self.state[nn_idx]), count + 1)
+            });
+        if count > 0 { sum / count as f64 } else { 0.0 }
+    }
+
+    /// Recompute the rates of all sites
+    fn rebuild_rates(&mut self) {
+        let rates = self
+            .state
+            .indices()
+            .map(|idx| self.site_rate(idx))
+            .collect();
+        self.rates = RateTree::new(rates);
+    }
+
+    /// Recompute the rates of a site and its nearest neighbors
+    fn refresh(&mut self, idx: S::Index) {
+        let neighbors: Vec<S::Index> = self.state.nearest_neighbors_index(idx).collect();
+        for idx in std::iter::once(idx).chain(neighbors) {
+            let rate = self.site_rate(idx);
+            self.rates.set(self.state.linear_index(idx), rate);
+        }
+    }
+
+    /// Perform a single hop given the total rate
+    fn event<R: Rng + ?Sized>(&mut self, total_rate: f64, rng: &mut R) {
+        let k = self.rates.find(rng.gen_range(0.0..total_rate));
+        let idx = self.state.site_index(k);
+        let n = self.state[idx];
+        if n == 0 {
+            return;
+        }
+        // Choose target proportionally to the hop rate
+        let neighbors: Vec<S::Index> = self.state.nearest_neighbors_index(idx).collect();
+        let weights: Vec<f64> = neighbors
+            .iter()
+            .map(|&nn_idx| self.hop_rate.rate(n, self.state[nn_idx]))
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            return;
+        }
+        let mut u = rng.gen_range(0.0..total_weight);
+        let Some(nn_idx) = neighbors
+            .into_iter()
+            .zip(&weights)
+            .find_map(|(nn_idx, &w)| {
+                if u < w {
+                    Some(nn_idx)
+                } else {
+                    u -= w;
+                    None
+                }
+            })
+        else {
+            return;
+        };
+        // Hop
+        self.state[idx] -= 1;
+        self.state[nn_idx] += 1;
+        self.refresh(idx);
+        self.refresh(nn_idx);
+    }
+}
+
+impl<S, H> StochasticProcess for BosonicHopping<S, H>
+where
+    S: SiteStateNN<Site = u32>,
+    H: HopRate,
+{
+    type State = S;
+
+    #[inline(always)]
+    fn state(&self) -> &Self::State {
+        &self.state
+    }
+
+    fn set_state(&mut self, state: Self::State) {
+        self.state = state;
+        self.rebuild_rates();
+    }
+
+    fn reset<I: InitialStateSpec<S>>(&mut self, spec: &mut I) {
+        spec.reset(&mut self.state);
+        self.rebuild_rates();
+        self.time = 0.0;
+    }
+
+    #[inline(always)]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    fn step<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let end = self.time + 1.0;
+        // Avoid accumulating rounding errors in the partial sums
+        self.rebuild_rates();
+        loop {
+            let total_rate = self.rates.total();
+            if total_rate <= 0.0 {
+                break;
+            }
+            let dt: f64 = Exp1.sample(rng);
+            if self.time + dt / total_rate > end {
+                break;
+            }
+            self.time += dt / total_rate;
+            self.event(total_rate, rng);
+        }
+        self.time = end;
+    }
+}
+
+/// Largest occupation number
+#[derive(Debug, Clone, Copy)]
+pub struct MaxOccupancy;
+
+impl<S> StateMeasurement<S> for MaxOccupancy
+where
+    S: SiteState<Site = u32>,
+{
+    type Type = u32;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        state.sites().copied().max().unwrap_or(0)
+    }
+}
+
+/// Fraction of all particles held by the most occupied site
+#[derive(Debug, Clone, Copy)]
+pub struct CondensateFraction;
+
+impl<S> StateMeasurement<S> for CondensateFraction
+where
+    S: SiteState<Site = u32>,
+{
+    type Type = f64;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let total: u64 = state.sites().map(|&n| n as u64).sum();
+        if total == 0 {
+            return 0.0;
+        }
+        MaxOccupancy.measure(state) as f64 / total as f64
+    }
+}
+
+/// Condensate detection
+/// Linear index of the site holding more than a given fraction of all particles, if any
+#[derive(Debug, Clone, Copy)]
+pub struct Condensate {
+    /// Minimal fraction of all particles held by the condensate, at least $1 / 2$ for uniqueness
+    pub fraction: f64,
+}
+
+impl<S> StateMeasurement<S> for Condensate
+where
+    S: SiteState<Site = u32>,
+{
+    type Type = Option<usize>;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let total: u64 = state.sites().map(|&n| n as u64).sum();
+        let (k, &max) = state.sites().enumerate().max_by_key(|&(_, n)| n)?;
+        (total > 0 && max as f64 > self.fraction * total as f64).then_some(k)
+    }
+}
+
+/// Occupation number distribution $P(n)$ indexed by $n$
+#[derive(Debug, Clone, Copy)]
+pub struct OccupationDistribution;
+
+impl<S> StateMeasurement<S> for OccupationDistribution
+where
+    S: SiteState<Site = u32>,
+{
+    type Type = Vec<f64>;
+
+    fn measure(&self, state: &S) -> Self::Type {
+        let occupations: Vec<usize> = state.sites().map(|&n| n as usize).collect();
+        histogram(&occupations)
+            .into_iter()
+            .map(|count| count as f64 / state.site_count() as f64)
+            .collect()
+    }
+}
@@ -0,0 +1,122 @@
+//! Active Site List
+//!
+
+use super::SiteState;
+use rand::Rng;
+
+/// Marker for sites absent from the list
+const ABSENT: usize = usize::MAX;
+
+/// Set of active sites stored by linear index
+/// - Constant time insertion and removal
+/// - Constant time uniform sampling of an active site
+#[derive(Debug, Clone)]
+pub struct ActiveSites {
+    /// Linear indices of the active sites
+    sites: Vec<usize>,
+    /// Position of each site in `sites` or `ABSENT`
+    position: Vec<usize>,
+}
+
+impl ActiveSites {
+    /// Create empty list for a state with `site_count` sites
+    pub fn new(site_count: usize) -> Self {
+        Self {
+            sites: Vec::new(),
+            position: vec![ABSENT; site_count],
+        }
+    }
+
+    /// Create list with all sites of a state satisfying `is_active`
+    pub fn from_state<S, F>(state: &S, is_active: F) -> Self
+    where
+        S: SiteState,
+        F: Fn(&S::Site) -> bool,
+    {
+        let mut active = Self::new(state.site_count());
+        active.rebuild(state, is_active);
+        active
+    }
+
+    /// Rebuild list from all sites of a state satisfying `is_active`
+    pub fn rebuild<S, F>(&mut self, state: &S, is_active: F)
+    where
+        S: SiteState,
+        F: Fn(&S::Site) -> bool,
+    {
+        self.clear();
+        self.position.resize(state.site_count(), ABSENT);
+        for (k, s) in state.sites().enumerate() {
+            if is_active(s) {
+                self.insert(k);
+            }
+        }
+    }
+
+    /// Number of active sites
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.sites.len()
+    }
+
+    /// Check if there are no active sites
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.sites.is_empty()
+    }
+
+    /// Check if a site is active
+    #[inline(always)]
+    pub fn contains(&self, k: usize) -> bool {
+        self.position[k] != ABSENT
+    }
+
+    /// Mark site as active, returns `false` if it already was
+    #[inline(always)]
+    pub fn insert(&mut self, k: usize) -> bool {
+        if self.contains(k) {
+            return false;
+        }
+        self.position[k] = self.sites.len();
+        self.sites.push(k);
+        true
+    }
+
+    /// Mark site as inactive, returns `false` if it already was
+    #[inline(always)]
+    pub fn remove(&mut self, k: usize) -> bool {
+        let pos = self.position[k];
+        if pos == ABSENT {
+            return false;
+        }
+        self.sites.swap_remove(pos);
+        if let Some(&moved) = self.sites.get(pos) {
+            self.position[moved] = pos;
+        }
+        self.position[k] = ABSENT;
+        true
+    }
+
+    /// Mark all sites as inactive
+    pub fn clear(&mut self) {
+        for &k in &self.sites {
+            self.position[k] = ABSENT;
+        }
+        self.sites.clear();
+    }
+
+    /// Choose an active site uniformly at random
+    #[inline(always)]
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<usize> {
+        match self.sites.len() {
+            0 => None,
+            n => Some(self.sites[rng.gen_range(0..n)]),
+        }
+    }
+
+    /// Iterator over the linear indices of all active sites
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sites.iter().copied()
+    }
+}
@@ -0,0 +1,257 @@
+//! Graphs
+//!
+
+use super::{SiteState, SiteStateNN};
+use rand::Rng;
+use rand_distr::{Distribution, Uniform};
+use std::{
+    io::{self, BufRead},
+    ops::{Index, IndexMut},
+};
+
+/// Undirected graph topology stored as adjacency lists
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Create new graph with `node_count` isolated nodes
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            adjacency: vec![Vec::new(); node_count],
+        }
+    }
+
+    /// Create new graph with `node_count` nodes and given edges
+    pub fn from_edges<I>(node_count: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let mut graph = Self::new(node_count);
+        for (a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    /// Read an edge list with one whitespace separated pair of node indices per line
+    ///
+    /// Empty lines and lines starting with `#` or `%` are ignored
+    /// and the node count is one past the largest node index.
+    pub fn read_edge_list<B: BufRead>(reader: B) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid edge list line: {line:?}"),
+            )
+        };
+        let mut edges = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', '%']) {
+                continue;
+            }
+            let mut nodes = line.split_whitespace().map(str::parse::<usize>);
+            match (nodes.next(), nodes.next()) {
+                (Some(Ok(a)), Some(Ok(b))) => edges.push((a, b)),
+                _ => return Err(invalid(line)),
+            }
+        }
+        let node_count = edges.iter().map(|&(a, b)| a.max(b) + 1).max().unwrap_or(0);
+        Ok(Self::from_edges(node_count, edges))
+    }
+
+    /// Add undirected edge, ignoring self-loops and repeated edges
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        if a == b || self.adjacency[a].contains(&b) {
+            return;
+        }
+        self.adjacency[a].push(b);
+        self.adjacency[b].push(a);
+    }
+
+    /// Number of nodes
+    #[inline(always)]
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Number of edges
+    #[inline(always)]
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Neighbors of a given node
+    #[inline(always)]
+    pub fn neighbors(&self, k: usize) -> &[usize] {
+        &self.adjacency[k]
+    }
+
+    /// Degree of a given node
+    #[inline(always)]
+    pub fn degree(&self, k: usize) -> usize {
+        self.adjacency[k].len()
+    }
+
+    /// Iterator over all edges `(a, b)` with `a < b`
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(a, nn)| nn.iter().filter(move |&&b| a < b).map(move |&b| (a, b)))
+    }
+}
+
+/// State composed of sites on the nodes of a shared graph
+#[derive(Debug, Clone)]
+pub struct GraphState<'g, T>
+where
+    T: Clone + Copy,
+{
+    /// Graph topology
+    graph: &'g Graph,
+    /// Node states
+    state: Vec<T>,
+    /// Uniform distribution over all nodes
+    site_dist: Uniform<usize>,
+}
+
+impl<'g, T> GraphState<'g, T>
+where
+    T: Clone + Copy,
+{
+    /// Graph topology
+    #[inline(always)]
+    pub fn graph(&self) -> &'g Graph {
+        self.graph
+    }
+}
+
+impl<T> Index<usize> for GraphState<'_, T>
+where
+    T: Clone + Copy,
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.state[index]
+    }
+}
+
+impl<T> IndexMut<usize> for GraphState<'_, T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.state[index]
+    }
+}
+
+impl<T> Distribution<usize> for GraphState<'_, T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.site_dist.sample(rng)
+    }
+}
+
+impl<'g, T> SiteState for GraphState<'g, T>
+where
+    T: Clone + Copy,
+{
+    /// Shared graph topology
+    type Shape = &'g Graph;
+
+    type Index = usize;
+
+    type Site = T;
+
+    #[inline(always)]
+    fn site_count(&self) -> usize {
+        self.state.len()
+    }
+
+    #[inline(always)]
+    fn sites(&self) -> impl Iterator<Item = &Self::Site> {
+        self.state.iter()
+    }
+
+    #[inline(always)]
+    fn sites_mut(&mut self) -> impl Iterator<Item = &mut Self::Site> {
+        self.state.iter_mut()
+    }
+
+    #[inline(always)]
+    fn linear_index(&self, idx: Self::Index) -> usize {
+        idx
+    }
+
+    #[inline(always)]
+    fn site_index(&self, k: usize) -> Self::Index {
+        k
+    }
+
+    fn uniform(graph: Self::Shape, site: Self::Site) -> Self {
+        Self {
+            graph,
+            state: vec![site; graph.node_count()],
+            site_dist: Uniform::new(0, graph.node_count()),
+        }
+    }
+
+    fn random<D, R>(graph: Self::Shape, dist: &D, rng: &mut R) -> Self
+    where
+        D: Distribution<Self::Site>,
+        R: Rng + ?Sized,
+    {
+        Self {
+            graph,
+            state: dist.sample_iter(rng).take(graph.node_count()).collect(),
+            site_dist: Uniform::new(0, graph.node_count()),
+        }
+    }
+
+    fn set_uniform(&mut self, site: Self::Site) {
+        self.state.fill(site);
+    }
+
+    fn set_random<D: Distribution<Self::Site>, R: Rng + ?Sized>(&mut self, dist: &D, rng: &mut R) {
+        for (s, x) in self.sites_mut().zip((&dist).sample_iter(rng)) {
+            *s = x;
+        }
+    }
+}
+
+impl<T> SiteStateNN for GraphState<'_, T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn nearest_neighbors_index_pairs(&self) -> impl Iterator<Item = (Self::Index, Self::Index)> {
+        self.graph.edges()
+    }
+
+    #[inline(always)]
+    fn nearest_neighbors_index(&self, k: Self::Index) -> impl Iterator<Item = Self::Index> {
+        self.graph.neighbors(k).iter().copied()
+    }
+
+    #[inline(always)]
+    fn nearest_neighbors_pairs(&self) -> impl Iterator<Item = (&Self::Site, &Self::Site)> {
+        self.graph
+            .edges()
+            .map(|(a, b)| (&self.state[a], &self.state[b]))
+    }
+
+    #[inline(always)]
+    fn nearest_neighbors(&self, k: Self::Index) -> impl Iterator<Item = &Self::Site> {
+        self.graph.neighbors(k).iter().map(|&b| &self.state[b])
+    }
+}
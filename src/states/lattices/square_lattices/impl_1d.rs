@@ -1,8 +1,11 @@
 //! One-dimensional square lattice
 //!
 
-use super::{Periodicity, SquareLattice1D};
-use crate::states::{SimpleSwapDiffusion, SiteCharRepr, SiteState, SiteStateNN, lattices::Lattice};
+use super::{Periodicity, SquareLattice1D, drift_directions};
+use crate::states::{
+    DriftSwapDiffusion, OpenBoundaries, RateSwapDiffusion, SimpleSwapDiffusion, SiteCharRepr,
+    SiteState, SiteStateMetric, SiteStateNN, lattices::Lattice,
+};
 use ndarray::Array1;
 use ndarray_rand::RandomExt;
 use rand::Rng;
@@ -71,6 +74,16 @@ where
         self.sites_mut()
     }
 
+    #[inline(always)]
+    fn linear_index(&self, idx: Self::Index) -> usize {
+        idx
+    }
+
+    #[inline(always)]
+    fn site_index(&self, k: usize) -> Self::Index {
+        k
+    }
+
     #[inline(always)]
     fn uniform(length: Self::Shape, site: Self::Site) -> Self {
         Self {
@@ -107,6 +120,28 @@ where
     }
 }
 
+/// Steps past either end of each axis are cut
+impl<T> OpenBoundaries for SquareLattice1D<T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn open_neighbors_index(&self, i: Self::Index) -> impl Iterator<Item = Option<Self::Index>> {
+        [self.period.open_prev(i), self.period.open_next(i)].into_iter()
+    }
+}
+
+impl<T> SiteStateMetric for SquareLattice1D<T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn squared_distance(&self, a: Self::Index, b: Self::Index) -> f64 {
+        let d = self.period.distance(a, b);
+        (d * d) as f64
+    }
+}
+
 impl<T> SiteStateNN for SquareLattice1D<T>
 where
     T: Clone + Copy,
@@ -151,6 +186,20 @@ where
     }
 }
 
+impl<T> SquareLattice1D<T>
+where
+    T: Clone + Copy,
+{
+    /// Nearest neighbor in a given direction as sampled from [`drift_directions`]
+    #[inline(always)]
+    fn drift_neighbor(&self, i: usize, direction: usize) -> usize {
+        match direction {
+            0 => self.period.next(i),
+            _ => self.period.prev(i),
+        }
+    }
+}
+
 impl<T> SimpleSwapDiffusion for SquareLattice1D<T>
 where
     T: Clone + Copy,
@@ -161,12 +210,7 @@ where
             // Select random site
             let i = self.sample(rng);
             // Select random nearest neighbor
-            // let nn_idx = self.nearest_neighbors_index(i).choose(rng).unwrap();
-            // let nn_idx = match rng.gen::<bool>() {
-            //     false => self.period.prev(i),
-            //     true => self.period.next(i),
-            // };
-            let nn_idx = self.period.next(i);
+            let nn_idx = self.drift_neighbor(i, rng.gen_range(0..2));
             // Diffuse with coin flip
             if diffusion_coin.sample(rng) {
                 self.state.swap(i, nn_idx)
@@ -174,3 +218,62 @@ where
         }
     }
 }
+
+/// Drift $v \in [-1, 1]$ along the chain, with $v = 1$ always moving particles to the next site
+impl<T> DriftSwapDiffusion for SquareLattice1D<T>
+where
+    T: Clone + Copy,
+{
+    type Drift = f64;
+
+    fn diffuse_with_drift<P, R>(
+        &mut self,
+        diffusion_coin: Bernoulli,
+        drift: &Self::Drift,
+        is_particle: P,
+        rng: &mut R,
+    ) where
+        P: Fn(&Self::Site) -> bool,
+        R: Rng + ?Sized,
+    {
+        let direction_dist = drift_directions(&[*drift]);
+        // Loop on random sites
+        for _ in 0..self.site_count() {
+            // Select random site, only particles move
+            let i = self.sample(rng);
+            if !is_particle(&self.state[i]) {
+                continue;
+            }
+            // Select nearest neighbor according to drift
+            let nn_idx = self.drift_neighbor(i, direction_dist.sample(&mut *rng));
+            // Diffuse with coin flip
+            if diffusion_coin.sample(rng) {
+                self.state.swap(i, nn_idx)
+            }
+        }
+    }
+}
+
+impl<T> RateSwapDiffusion for SquareLattice1D<T>
+where
+    T: Clone + Copy,
+{
+    fn diffuse_with_rate<F, R>(&mut self, rate: F, drift: &Self::Drift, rng: &mut R)
+    where
+        F: Fn(&Self::Site, &Self::Site) -> f64,
+        R: Rng + ?Sized,
+    {
+        let direction_dist = drift_directions(&[*drift]);
+        // Loop on random sites
+        for _ in 0..self.site_count() {
+            // Select random site
+            let i = self.sample(rng);
+            // Select nearest neighbor according to drift
+            let nn_idx = self.drift_neighbor(i, direction_dist.sample(&mut *rng));
+            // Diffuse with content dependent rate
+            if rng.r#gen::<f64>() < rate(&self.state[i], &self.state[nn_idx]) {
+                self.state.swap(i, nn_idx)
+            }
+        }
+    }
+}
@@ -1,8 +1,11 @@
 //! Two-dimensional square lattice
 //!
 
-use super::{Periodicity, SquareLattice2D};
-use crate::states::{SimpleSwapDiffusion, SiteCharRepr, SiteState, SiteStateNN, lattices::Lattice};
+use super::{Periodicity, SquareLattice2D, drift_directions};
+use crate::states::{
+    DriftSwapDiffusion, OpenBoundaries, RateSwapDiffusion, SimpleSwapDiffusion, SiteCharRepr,
+    SiteState, SiteStateMetric, SiteStateNN, lattices::Lattice,
+};
 use itertools::Itertools;
 use ndarray::{Array2, Axis};
 use ndarray_rand::RandomExt;
@@ -73,6 +76,18 @@ where
         self.sites_mut()
     }
 
+    #[inline(always)]
+    fn linear_index(&self, [i, j]: Self::Index) -> usize {
+        let l = self.length();
+        i * l + j
+    }
+
+    #[inline(always)]
+    fn site_index(&self, k: usize) -> Self::Index {
+        let l = self.length();
+        [k / l, k % l]
+    }
+
     #[inline(always)]
     fn uniform(side_length: Self::Shape, site: Self::Site) -> Self {
         Self {
@@ -109,6 +124,37 @@ where
     }
 }
 
+/// Steps past either end of each axis are cut
+impl<T> OpenBoundaries for SquareLattice2D<T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn open_neighbors_index(
+        &self,
+        [i, j]: Self::Index,
+    ) -> impl Iterator<Item = Option<Self::Index>> {
+        [
+            self.period.open_prev(j).map(|j| [i, j]),
+            self.period.open_next(j).map(|j| [i, j]),
+            self.period.open_prev(i).map(|i| [i, j]),
+            self.period.open_next(i).map(|i| [i, j]),
+        ]
+        .into_iter()
+    }
+}
+
+impl<T> SiteStateMetric for SquareLattice2D<T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn squared_distance(&self, [ai, aj]: Self::Index, [bi, bj]: Self::Index) -> f64 {
+        let (di, dj) = (self.period.distance(ai, bi), self.period.distance(aj, bj));
+        (di * di + dj * dj) as f64
+    }
+}
+
 impl<T> SiteStateNN for SquareLattice2D<T>
 where
     T: Clone + Copy,
@@ -158,7 +204,7 @@ where
     }
 }
 
-/// Display Contact Process 2D state
+/// Display 2D state
 impl<T> Display for SquareLattice2D<T>
 where
     T: Clone + Copy + SiteCharRepr,
@@ -206,6 +252,22 @@ where
 //     }
 // }
 
+impl<T> SquareLattice2D<T>
+where
+    T: Clone + Copy,
+{
+    /// Nearest neighbor in a given direction as sampled from [`drift_directions`]
+    #[inline(always)]
+    fn drift_neighbor(&self, [i, j]: [usize; 2], direction: usize) -> [usize; 2] {
+        match direction {
+            0 => [self.period.next(i), j],
+            1 => [self.period.prev(i), j],
+            2 => [i, self.period.next(j)],
+            _ => [i, self.period.prev(j)],
+        }
+    }
+}
+
 impl<T> SimpleSwapDiffusion for SquareLattice2D<T>
 where
     T: Clone + Copy,
@@ -214,12 +276,9 @@ where
         // Loop on random sites
         for _ in 0..self.site_count() {
             // Select random site
-            let idx @ [i, j] = self.sample(rng);
+            let idx = self.sample(rng);
             // Select random nearest neighbor
-            let nn_idx = match rng.r#gen() {
-                true => [i, self.period.next(j)],
-                false => [self.period.next(i), j],
-            };
+            let nn_idx = self.drift_neighbor(idx, rng.gen_range(0..4));
             // Diffuse with coin flip
             if diffusion_coin.sample(rng) {
                 self.state.swap(idx, nn_idx)
@@ -227,3 +286,62 @@ where
         }
     }
 }
+
+/// Drift vector $(v_0, v_1)$ with one component per axis
+impl<T> DriftSwapDiffusion for SquareLattice2D<T>
+where
+    T: Clone + Copy,
+{
+    type Drift = [f64; 2];
+
+    fn diffuse_with_drift<P, R>(
+        &mut self,
+        diffusion_coin: Bernoulli,
+        drift: &Self::Drift,
+        is_particle: P,
+        rng: &mut R,
+    ) where
+        P: Fn(&Self::Site) -> bool,
+        R: Rng + ?Sized,
+    {
+        let direction_dist = drift_directions(drift);
+        // Loop on random sites
+        for _ in 0..self.site_count() {
+            // Select random site, only particles move
+            let idx = self.sample(rng);
+            if !is_particle(&self.state[idx]) {
+                continue;
+            }
+            // Select nearest neighbor according to drift
+            let nn_idx = self.drift_neighbor(idx, direction_dist.sample(&mut *rng));
+            // Diffuse with coin flip
+            if diffusion_coin.sample(rng) {
+                self.state.swap(idx, nn_idx)
+            }
+        }
+    }
+}
+
+impl<T> RateSwapDiffusion for SquareLattice2D<T>
+where
+    T: Clone + Copy,
+{
+    fn diffuse_with_rate<F, R>(&mut self, rate: F, drift: &Self::Drift, rng: &mut R)
+    where
+        F: Fn(&Self::Site, &Self::Site) -> f64,
+        R: Rng + ?Sized,
+    {
+        let direction_dist = drift_directions(drift);
+        // Loop on random sites
+        for _ in 0..self.site_count() {
+            // Select random site
+            let idx = self.sample(rng);
+            // Select nearest neighbor according to drift
+            let nn_idx = self.drift_neighbor(idx, direction_dist.sample(&mut *rng));
+            // Diffuse with content dependent rate
+            if rng.r#gen::<f64>() < rate(&self.state[idx], &self.state[nn_idx]) {
+                self.state.swap(idx, nn_idx)
+            }
+        }
+    }
+}
@@ -1,8 +1,11 @@
 //! Three-dimensional square lattice
 //!
 
-use super::{Periodicity, SquareLattice3D};
-use crate::states::{SimpleSwapDiffusion, SiteState, SiteStateNN, lattices::Lattice};
+use super::{Periodicity, SquareLattice3D, drift_directions};
+use crate::states::{
+    DriftSwapDiffusion, OpenBoundaries, RateSwapDiffusion, SimpleSwapDiffusion, SiteState,
+    SiteStateMetric, SiteStateNN, lattices::Lattice,
+};
 use itertools::Itertools;
 use ndarray::{Array3, Axis};
 use ndarray_rand::RandomExt;
@@ -74,6 +77,18 @@ where
         self.sites_mut()
     }
 
+    #[inline(always)]
+    fn linear_index(&self, [i, j, k]: Self::Index) -> usize {
+        let l = self.length();
+        (i * l + j) * l + k
+    }
+
+    #[inline(always)]
+    fn site_index(&self, m: usize) -> Self::Index {
+        let l = self.length();
+        [m / (l * l), (m / l) % l, m % l]
+    }
+
     #[inline(always)]
     fn uniform(side_length: Self::Shape, site: Self::Site) -> Self {
         Self {
@@ -110,6 +125,43 @@ where
     }
 }
 
+/// Steps past either end of each axis are cut
+impl<T> OpenBoundaries for SquareLattice3D<T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn open_neighbors_index(
+        &self,
+        [i, j, k]: Self::Index,
+    ) -> impl Iterator<Item = Option<Self::Index>> {
+        [
+            self.period.open_prev(k).map(|k| [i, j, k]),
+            self.period.open_next(k).map(|k| [i, j, k]),
+            self.period.open_prev(j).map(|j| [i, j, k]),
+            self.period.open_next(j).map(|j| [i, j, k]),
+            self.period.open_prev(i).map(|i| [i, j, k]),
+            self.period.open_next(i).map(|i| [i, j, k]),
+        ]
+        .into_iter()
+    }
+}
+
+impl<T> SiteStateMetric for SquareLattice3D<T>
+where
+    T: Clone + Copy,
+{
+    #[inline(always)]
+    fn squared_distance(&self, [ai, aj, ak]: Self::Index, [bi, bj, bk]: Self::Index) -> f64 {
+        let (di, dj, dk) = (
+            self.period.distance(ai, bi),
+            self.period.distance(aj, bj),
+            self.period.distance(ak, bk),
+        );
+        (di * di + dj * dj + dk * dk) as f64
+    }
+}
+
 impl<T> SiteStateNN for SquareLattice3D<T>
 where
     T: Clone + Copy,
@@ -167,21 +219,35 @@ where
     }
 }
 
+impl<T> SquareLattice3D<T>
+where
+    T: Clone + Copy,
+{
+    /// Nearest neighbor in a given direction as sampled from [`drift_directions`]
+    #[inline(always)]
+    fn drift_neighbor(&self, [i, j, k]: [usize; 3], direction: usize) -> [usize; 3] {
+        match direction {
+            0 => [self.period.next(i), j, k],
+            1 => [self.period.prev(i), j, k],
+            2 => [i, self.period.next(j), k],
+            3 => [i, self.period.prev(j), k],
+            4 => [i, j, self.period.next(k)],
+            _ => [i, j, self.period.prev(k)],
+        }
+    }
+}
+
 impl<T> SimpleSwapDiffusion for SquareLattice3D<T>
 where
     T: Clone + Copy,
 {
     fn diffuse<R: rand::prelude::Rng + ?Sized>(&mut self, diffusion_coin: Bernoulli, rng: &mut R) {
-        let direction_dist = Uniform::new(0, 3);
         // Loop on random sites
         for _ in 0..self.site_count() {
-            let idx @ [i, j, k] = self.sample(rng);
+            // Select random site
+            let idx = self.sample(rng);
             // Select random nearest neighbor
-            let nn_idx = match direction_dist.sample(&mut *rng) {
-                0 => [i, j, self.period.next(k)],
-                1 => [i, self.period.next(j), k],
-                _ => [self.period.next(i), j, k],
-            };
+            let nn_idx = self.drift_neighbor(idx, rng.gen_range(0..6));
             // Diffuse with coin flip
             if diffusion_coin.sample(rng) {
                 self.state.swap(idx, nn_idx)
@@ -189,3 +255,62 @@ where
         }
     }
 }
+
+/// Drift vector $(v_0, v_1, v_2)$ with one component per axis
+impl<T> DriftSwapDiffusion for SquareLattice3D<T>
+where
+    T: Clone + Copy,
+{
+    type Drift = [f64; 3];
+
+    fn diffuse_with_drift<P, R>(
+        &mut self,
+        diffusion_coin: Bernoulli,
+        drift: &Self::Drift,
+        is_particle: P,
+        rng: &mut R,
+    ) where
+        P: Fn(&Self::Site) -> bool,
+        R: Rng + ?Sized,
+    {
+        let direction_dist = drift_directions(drift);
+        // Loop on random sites
+        for _ in 0..self.site_count() {
+            // Select random site, only particles move
+            let idx = self.sample(rng);
+            if !is_particle(&self.state[idx]) {
+                continue;
+            }
+            // Select nearest neighbor according to drift
+            let nn_idx = self.drift_neighbor(idx, direction_dist.sample(&mut *rng));
+            // Diffuse with coin flip
+            if diffusion_coin.sample(rng) {
+                self.state.swap(idx, nn_idx)
+            }
+        }
+    }
+}
+
+impl<T> RateSwapDiffusion for SquareLattice3D<T>
+where
+    T: Clone + Copy,
+{
+    fn diffuse_with_rate<F, R>(&mut self, rate: F, drift: &Self::Drift, rng: &mut R)
+    where
+        F: Fn(&Self::Site, &Self::Site) -> f64,
+        R: Rng + ?Sized,
+    {
+        let direction_dist = drift_directions(drift);
+        // Loop on random sites
+        for _ in 0..self.site_count() {
+            // Select random site
+            let idx = self.sample(rng);
+            // Select nearest neighbor according to drift
+            let nn_idx = self.drift_neighbor(idx, direction_dist.sample(&mut *rng));
+            // Diffuse with content dependent rate
+            if rng.r#gen::<f64>() < rate(&self.state[idx], &self.state[nn_idx]) {
+                self.state.swap(idx, nn_idx)
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 //! Square Lattices
 //!
 
+use crate::states::{InitialStateSpec, LongRangeDiffusion, SiteState};
 use ndarray::{Array, Axis, Dimension, Ix1, Ix2, Ix3};
-use rand_distr::Uniform;
+use rand::Rng;
+use rand_distr::{Distribution, Uniform, WeightedIndex};
 
 /// Periodicity
 ///
@@ -38,12 +40,65 @@ impl Periodicity {
     pub fn next(&self, k: usize) -> usize {
         self.next[k]
     }
+
+    /// Length of the periodic dimension
+    #[inline(always)]
+    pub fn length(&self) -> usize {
+        self.next.len()
+    }
+
+    /// Get index `n` steps after `k`
+    #[inline(always)]
+    pub fn forward(&self, k: usize, n: usize) -> usize {
+        (k + n % self.length()) % self.length()
+    }
+
+    /// Get index `n` steps before `k`
+    #[inline(always)]
+    pub fn backward(&self, k: usize, n: usize) -> usize {
+        (k + self.length() - n % self.length()) % self.length()
+    }
+
+    /// Get previous index without wrapping around, `None` at the first index
+    #[inline(always)]
+    pub fn open_prev(&self, k: usize) -> Option<usize> {
+        k.checked_sub(1)
+    }
+
+    /// Get next index without wrapping around, `None` at the last index
+    #[inline(always)]
+    pub fn open_next(&self, k: usize) -> Option<usize> {
+        (k + 1 < self.length()).then_some(k + 1)
+    }
+
+    /// Distance between two indices with the minimum image convention
+    #[inline(always)]
+    pub fn distance(&self, a: usize, b: usize) -> usize {
+        let d = a.abs_diff(b);
+        d.min(self.length() - d)
+    }
+}
+
+/// Distribution over the $2d$ nearest neighbor directions of a $d$-dimensional square lattice
+/// given a drift vector with components $v_a \in [-1, 1]$
+///
+/// Direction $2a$ is the next site along axis $a$, chosen with probability $(1 + v_a) / 2d$,
+/// and direction $2a + 1$ is the previous one, chosen with probability $(1 - v_a) / 2d$.
+///
+/// Panics if a drift component lies outside $[-1, 1]$.
+pub(crate) fn drift_directions(drift: &[f64]) -> WeightedIndex<f64> {
+    assert!(
+        drift.iter().all(|v| (-1.0..=1.0).contains(v)),
+        "Drift components must lie in [-1, 1]"
+    );
+    WeightedIndex::new(drift.iter().flat_map(|v| [1.0 + v, 1.0 - v]))
+        .expect("Drift directions must have positive total weight")
 }
 
 /// Square Lattice
 /// TODO: Generalize all lattice methods to an arbitrary dimensionality
 /// TODO: Is there a way to avoid these auxiliary fields, maybe lazily creating and caching them.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SquareLattice<T, D>
 where
     T: Clone + Copy,
@@ -91,6 +146,91 @@ where
     pub fn fill(&mut self, site: T) {
         self.state.fill(site)
     }
+
+    /// Linear index of the central site
+    #[inline(always)]
+    pub fn center(&self) -> usize {
+        let length = self.length();
+        (0..self.state.ndim()).fold(0, |k, _| k * length + length / 2)
+    }
+}
+
+/// Single seed site at the center of the lattice over a uniform background
+#[derive(Debug)]
+pub struct CenterSeed<T: Clone + Copy> {
+    /// State of all other sites
+    pub background: T,
+    /// State of the central site
+    pub seed: T,
+}
+
+impl<T, D> InitialStateSpec<SquareLattice<T, D>> for CenterSeed<T>
+where
+    T: Clone + Copy,
+    D: Dimension,
+    SquareLattice<T, D>: SiteState<Site = T>,
+{
+    fn construct(
+        &mut self,
+        shape: <SquareLattice<T, D> as SiteState>::Shape,
+    ) -> SquareLattice<T, D> {
+        let mut lattice = SquareLattice::<T, D>::uniform(shape, self.background);
+        let idx = lattice.site_index(lattice.center());
+        lattice[idx] = self.seed;
+        lattice
+    }
+
+    fn reset(&mut self, lattice: &mut SquareLattice<T, D>) {
+        lattice.set_uniform(self.background);
+        let idx = lattice.site_index(lattice.center());
+        lattice[idx] = self.seed;
+    }
+}
+
+/// Hops along the lattice axes wrapping around the periodic boundaries
+impl<T, D> LongRangeDiffusion for SquareLattice<T, D>
+where
+    T: Clone + Copy,
+    D: Dimension,
+    SquareLattice<T, D>: SiteState<Site = T>,
+{
+    fn diffuse_long_range<X, F, R>(&mut self, distance_dist: &X, rate: F, rng: &mut R)
+    where
+        X: Distribution<f64>,
+        F: Fn(&T, &T) -> f64,
+        R: Rng + ?Sized,
+    {
+        let (length, ndim) = (self.length(), self.state.ndim());
+        let site_count = self.state.len();
+        let direction_dist = Uniform::new(0, 2 * ndim);
+        let period = &self.period;
+        let sites = self
+            .state
+            .as_slice_mut()
+            .expect("Lattice state must be in standard layout");
+        // Loop on random sites
+        for _ in 0..site_count {
+            // Select random site
+            let k = rng.gen_range(0..site_count);
+            // Select random distance and direction
+            let distance = distance_dist.sample(rng) as usize;
+            if distance == 0 {
+                continue;
+            }
+            let direction = direction_dist.sample(rng);
+            let stride = length.pow((ndim - 1 - direction / 2) as u32);
+            let coord = (k / stride) % length;
+            let target_coord = match direction % 2 {
+                0 => period.forward(coord, distance),
+                _ => period.backward(coord, distance),
+            };
+            let target = k - coord * stride + target_coord * stride;
+            // Diffuse with content dependent rate
+            if rng.r#gen::<f64>() < rate(&sites[k], &sites[target]) {
+                sites.swap(k, target);
+            }
+        }
+    }
 }
 
 /// One-dimensional Lattice
@@ -38,6 +38,18 @@ pub trait SiteState:
     /// Mutable iterator over all sites
     fn sites_mut(&mut self) -> impl Iterator<Item = &mut Self::Site>;
 
+    /// Linear position of a given site index in `0..site_count()`
+    fn linear_index(&self, idx: Self::Index) -> usize;
+
+    /// Site index at a given linear position in `0..site_count()`
+    fn site_index(&self, k: usize) -> Self::Index;
+
+    /// Iterator over all site indices in linear order
+    #[inline(always)]
+    fn indices(&self) -> impl Iterator<Item = Self::Index> {
+        (0..self.site_count()).map(|k| self.site_index(k))
+    }
+
     /// Create new state with sites in the same state
     fn uniform(shape: Self::Shape, site: Self::Site) -> Self;
 
@@ -148,6 +160,40 @@ where
     }
 }
 
+/// Distribute a fixed number of particles over bosonic sites, each on a uniformly random site
+#[derive(Debug)]
+pub struct RandomParticles<'a, R: Rng + ?Sized> {
+    /// Total number of particles
+    pub count: u32,
+    rng: &'a mut R,
+}
+
+impl<'a, R: Rng + ?Sized> RandomParticles<'a, R> {
+    pub fn new(count: u32, rng: &'a mut R) -> Self {
+        Self { count, rng }
+    }
+}
+
+impl<S, R> InitialStateSpec<S> for RandomParticles<'_, R>
+where
+    S: SiteState<Site = u32>,
+    R: Rng + ?Sized,
+{
+    fn construct(&mut self, shape: <S as SiteState>::Shape) -> S {
+        let mut state = S::uniform(shape, 0);
+        self.reset(&mut state);
+        state
+    }
+
+    fn reset(&mut self, state: &mut S) {
+        state.set_uniform(0);
+        for _ in 0..self.count {
+            let idx = state.sample(self.rng);
+            state[idx] += 1;
+        }
+    }
+}
+
 /// Defines default site reset specification
 pub trait DefaultSiteStateReset: SiteState
 where
@@ -171,10 +217,88 @@ pub trait StateMeasurement<S: SiteState> {
 }
 
 /// State swap diffusion
+/// Each sweep visits as many random sites as the state has and, with a coin flip,
+/// swaps each with a uniformly chosen nearest neighbor.
+///
+/// Swaps ignore the contents of the sites, so a particle moves as often by being visited
+/// as by neighboring a visited vacancy. Biasing the chosen direction would move vacancies
+/// the same way and cancel out for particles, hence the drift of [`DriftSwapDiffusion`].
 pub trait SimpleSwapDiffusion {
+    /// Isotropic diffusion: swap with a nearest neighbor chosen uniformly
     fn diffuse<R: Rng + ?Sized>(&mut self, diffusion_coin: Bernoulli, rng: &mut R);
 }
 
+/// Particle swap diffusion with a drift
+/// Each sweep visits as many random sites as the state has and, with a coin flip,
+/// swaps each visited particle with a nearest neighbor chosen according to the drift.
+/// Visited vacancies do not move, so that the drift biases particles only.
+pub trait DriftSwapDiffusion: SiteState + SimpleSwapDiffusion {
+    /// Drift vector biasing the direction in which visited particles move,
+    /// zero for isotropic diffusion
+    type Drift: Default;
+
+    /// Biased diffusion of the sites for which `is_particle` holds
+    fn diffuse_with_drift<P, R>(
+        &mut self,
+        diffusion_coin: Bernoulli,
+        drift: &Self::Drift,
+        is_particle: P,
+        rng: &mut R,
+    ) where
+        P: Fn(&Self::Site) -> bool,
+        R: Rng + ?Sized;
+}
+
+/// State swap diffusion with rates depending on the contents of the swapped sites
+///
+/// A drift only biases the motion of particles if vacancies do not move on their own,
+/// i.e. if the rate vanishes for visited vacancies, e.g. [`species_rate`] or [`exclusion_rate`]
+/// given a zero rate for vacancies.
+pub trait RateSwapDiffusion: DriftSwapDiffusion {
+    /// Diffusion where each visited site swaps with the nearest neighbor chosen according to the drift
+    /// with probability `rate(visited, neighbor)`
+    fn diffuse_with_rate<F, R>(&mut self, rate: F, drift: &Self::Drift, rng: &mut R)
+    where
+        F: Fn(&Self::Site, &Self::Site) -> f64,
+        R: Rng + ?Sized;
+}
+
+/// State swap diffusion over long distances, e.g. Lévy flights
+pub trait LongRangeDiffusion: SiteState {
+    /// Each visited site swaps with probability `rate(visited, target)` with the target site
+    /// at a distance drawn from `distance_dist`, rounded down, in a uniformly chosen direction
+    ///
+    /// Power-law hop lengths are obtained with e.g. [`rand_distr::Zeta`] or [`rand_distr::Pareto`].
+    fn diffuse_long_range<D, F, R>(&mut self, distance_dist: &D, rate: F, rng: &mut R)
+    where
+        D: Distribution<f64>,
+        F: Fn(&Self::Site, &Self::Site) -> f64,
+        R: Rng + ?Sized;
+}
+
+/// Rate function moving the content of each visited site with a rate depending only on its type
+/// e.g. zero for vacancies and a larger rate for faster species
+#[inline(always)]
+pub fn species_rate<T, F>(rate: F) -> impl Fn(&T, &T) -> f64
+where
+    F: Fn(&T) -> f64,
+{
+    move |site, _| rate(site)
+}
+
+/// Rate function with exclusion: the content of each visited site only hops into empty neighbors
+#[inline(always)]
+pub fn exclusion_rate<T, F, E>(rate: F, is_empty: E) -> impl Fn(&T, &T) -> f64
+where
+    F: Fn(&T) -> f64,
+    E: Fn(&T) -> bool,
+{
+    move |site, neighbor| match is_empty(neighbor) {
+        true => rate(site),
+        false => 0.0,
+    }
+}
+
 /// States composed of sites with a notion of "nearest neighborhood"
 /// - Iteration over all nearest neighbors pairs
 /// - Iteration over the nearest neighbors of given site
@@ -192,5 +316,33 @@ pub trait SiteStateNN: SiteState {
     fn nearest_neighbors(&self, idx: Self::Index) -> impl Iterator<Item = &Self::Site>;
 }
 
+/// States with a notion of distance between sites
+pub trait SiteStateMetric: SiteState {
+    /// Squared distance between two sites
+    fn squared_distance(&self, a: Self::Index, b: Self::Index) -> f64;
+}
+
+/// States whose nearest neighbors bonds wrapping around a periodic boundary can be cut
+pub trait OpenBoundaries: SiteStateNN {
+    /// Iterator over the nearest neighbors of a given site, one per direction,
+    /// `None` for the steps wrapping around the boundary
+    ///
+    /// Directions rather than index pairs tell the wrapping bond apart from the inner one
+    /// on axes of length two, where both join the same sites.
+    fn open_neighbors_index(&self, idx: Self::Index) -> impl Iterator<Item = Option<Self::Index>>;
+}
+
+/// Site types
+pub mod sites;
+
+/// Clusters
+pub mod clusters;
+
+/// Active site lists
+pub mod active_sites;
+
 /// Lattices
 pub mod lattices;
+
+/// Graphs
+pub mod graphs;
@@ -0,0 +1,220 @@
+//! Site Types
+//!
+
+use super::SiteCharRepr;
+use rand::Rng;
+use rand_distr::{Bernoulli, Distribution, Standard};
+
+/// Ising spin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsingSpin {
+    Down,
+    Up,
+}
+
+impl IsingSpin {
+    /// Spin value $\sigma \in \{-1, +1\}$
+    #[inline(always)]
+    pub fn value(&self) -> i32 {
+        match self {
+            IsingSpin::Down => -1,
+            IsingSpin::Up => 1,
+        }
+    }
+
+    /// Opposite spin
+    #[inline(always)]
+    pub fn flipped(&self) -> Self {
+        match self {
+            IsingSpin::Down => IsingSpin::Up,
+            IsingSpin::Up => IsingSpin::Down,
+        }
+    }
+}
+
+/// Uniformly random spin
+impl Distribution<IsingSpin> for Standard {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IsingSpin {
+        match rng.r#gen() {
+            false => IsingSpin::Down,
+            true => IsingSpin::Up,
+        }
+    }
+}
+
+impl SiteCharRepr for IsingSpin {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            IsingSpin::Down => ' ',
+            IsingSpin::Up => '█',
+        }
+    }
+}
+
+/// Potts spin taking one of $q$ states `0..q`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PottsSpin(pub u8);
+
+/// Uniform distribution over the $q$ states of a Potts spin
+#[derive(Debug, Clone, Copy)]
+pub struct PottsStates {
+    q: u8,
+}
+
+impl PottsStates {
+    /// Create uniform distribution over `q` states
+    ///
+    /// Panics if `q` is zero.
+    pub fn new(q: u8) -> Self {
+        assert!(q > 0, "Potts spin must have at least one state");
+        Self { q }
+    }
+
+    /// Number of states
+    #[inline(always)]
+    pub fn q(&self) -> u8 {
+        self.q
+    }
+}
+
+impl Distribution<PottsSpin> for PottsStates {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PottsSpin {
+        PottsSpin(rng.gen_range(0..self.q))
+    }
+}
+
+impl SiteCharRepr for PottsSpin {
+    #[inline(always)]
+    fn char(&self) -> char {
+        char::from_digit(self.0 as u32, 36).unwrap_or('?')
+    }
+}
+
+/// Site occupancy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Occupancy {
+    #[default]
+    Empty,
+    Occupied,
+}
+
+impl Occupancy {
+    /// Check if the site is occupied
+    #[inline(always)]
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, Occupancy::Occupied)
+    }
+}
+
+impl From<bool> for Occupancy {
+    #[inline(always)]
+    fn from(occupied: bool) -> Self {
+        match occupied {
+            false => Occupancy::Empty,
+            true => Occupancy::Occupied,
+        }
+    }
+}
+
+/// Occupied with the coin probability
+impl Distribution<Occupancy> for Bernoulli {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Occupancy {
+        Occupancy::from(Distribution::<bool>::sample(self, rng))
+    }
+}
+
+impl From<u8> for Occupancy {
+    /// Any nonzero color is occupied
+    #[inline(always)]
+    fn from(color: u8) -> Self {
+        Occupancy::from(color != 0)
+    }
+}
+
+impl From<Occupancy> for u8 {
+    #[inline(always)]
+    fn from(site: Occupancy) -> Self {
+        site.is_occupied() as u8
+    }
+}
+
+impl SiteCharRepr for Occupancy {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            Occupancy::Empty => ' ',
+            Occupancy::Occupied => '█',
+        }
+    }
+}
+
+/// Site holding at most one particle of species A or B
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Particle {
+    #[default]
+    Empty,
+    A,
+    B,
+}
+
+impl Particle {
+    /// Check if the site holds no particle
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Particle::Empty)
+    }
+}
+
+/// Independent random sites holding A with probability `a` and B with probability `b`
+#[derive(Debug, Clone, Copy)]
+pub struct ParticleMix {
+    /// Density of A particles
+    pub a: f64,
+    /// Density of B particles
+    pub b: f64,
+}
+
+impl Distribution<Particle> for ParticleMix {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Particle {
+        let u: f64 = rng.r#gen();
+        if u < self.a {
+            Particle::A
+        } else if u < self.a + self.b {
+            Particle::B
+        } else {
+            Particle::Empty
+        }
+    }
+}
+
+impl SiteCharRepr for Particle {
+    #[inline(always)]
+    fn char(&self) -> char {
+        match self {
+            Particle::Empty => ' ',
+            Particle::A => 'A',
+            Particle::B => 'B',
+        }
+    }
+}
+
+/// Color index as a base 36 digit
+impl SiteCharRepr for u8 {
+    #[inline(always)]
+    fn char(&self) -> char {
+        char::from_digit(*self as u32, 36).unwrap_or('?')
+    }
+}
+
+/// Particle number of a bosonic site as a base 36 digit, `+` beyond
+impl SiteCharRepr for u32 {
+    #[inline(always)]
+    fn char(&self) -> char {
+        char::from_digit(*self, 36).unwrap_or('+')
+    }
+}
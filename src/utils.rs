@@ -61,3 +61,12 @@ impl Display for Timer {
         )
     }
 }
+
+/// Histogram of nonnegative integer values indexed by value
+pub fn histogram(values: &[usize]) -> Vec<usize> {
+    let mut counts = vec![0; values.iter().max().map_or(0, |&m| m + 1)];
+    for &v in values {
+        counts[v] += 1;
+    }
+    counts
+}
@@ -0,0 +1,61 @@
+use artificial_systems::{
+    models::swendsen_wang::SwendsenWang,
+    states::{
+        SiteState,
+        clusters::{Clusters, UnionFind},
+        lattices::square_lattices::{SquareLattice1D, SquareLattice2D},
+    },
+};
+use rand::{SeedableRng, rngs::StdRng};
+use rand_distr::{Bernoulli, Uniform};
+
+#[test]
+fn union_find_merges_sets() {
+    let mut forest = UnionFind::new(6);
+    forest.union(0, 1);
+    forest.union(2, 3);
+    forest.union(1, 3);
+    assert_eq!(forest.find(0), forest.find(2));
+    assert_ne!(forest.find(0), forest.find(4));
+    assert_eq!(forest.set_size(3), 4);
+    assert_eq!(forest.set_size(5), 1);
+    // Merging within a set changes nothing
+    let root = forest.find(0);
+    assert_eq!(forest.union(1, 2), root);
+    assert_eq!(forest.set_size(0), 4);
+
+    let clusters = forest.clusters();
+    assert_eq!(clusters.count(), 3);
+    assert_eq!(clusters.sizes, [4, 1, 1]);
+    assert_eq!(clusters.labels, [0, 0, 0, 0, 1, 2]);
+}
+
+#[test]
+fn clusters_join_across_periodic_boundary() {
+    let mut state = SquareLattice1D::uniform(7, 0u8);
+    for i in [0, 1, 3, 6] {
+        state[i] = 1;
+    }
+    let clusters = Clusters::identify(&state, |a, b| a == b);
+    // {6, 0, 1}, {2}, {3}, {4, 5}
+    assert_eq!(clusters.count(), 4);
+    assert_eq!(clusters.largest(), 3);
+    assert_eq!(clusters.label(&state, 6), clusters.label(&state, 0));
+    assert_eq!(clusters.label(&state, 4), clusters.label(&state, 5));
+    assert_ne!(clusters.label(&state, 1), clusters.label(&state, 3));
+    assert_eq!(clusters.mean_size_estimator(), (9 + 1 + 1 + 4) as f64 / 7.0);
+}
+
+#[test]
+fn swendsen_wang_bond_extremes() {
+    let mut rng = StdRng::seed_from_u64(26);
+    let mut state = SquareLattice2D::uniform(4, 1u8);
+    let update = SwendsenWang::new(Bernoulli::new(1.0).unwrap(), Uniform::new(0u8, 1));
+    let clusters = update.update(&mut state, &mut rng);
+    assert_eq!((clusters.count(), clusters.largest()), (1, 16));
+    assert!(state.sites().all(|&s| s == 0));
+
+    let update = SwendsenWang::new(Bernoulli::new(0.0).unwrap(), Uniform::new(0u8, 1));
+    let clusters = update.update(&mut state, &mut rng);
+    assert_eq!((clusters.count(), clusters.largest()), (16, 1));
+}
@@ -0,0 +1,84 @@
+use artificial_systems::{
+    models::{
+        StochasticProcess,
+        cellular_automata::life_like::{LifeLike, LifeLikeRule},
+    },
+    states::{SiteState, lattices::square_lattices::SquareLattice2D, sites::Occupancy},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+#[test]
+fn parse_rule_strings() {
+    assert_eq!("B3/S23".parse(), Ok(LifeLikeRule::conway()));
+    assert_eq!("b36/s23".parse(), Ok(LifeLikeRule::highlife()));
+    assert_eq!("B2/S".parse(), Ok(LifeLikeRule::seeds()));
+    assert_eq!(
+        "B5,6,7/S5,6,7".parse(),
+        Ok(LifeLikeRule::new(&[5, 6, 7], &[5, 6, 7]))
+    );
+    // Without commas every digit is a separate count
+    assert_eq!("B32/S".parse(), Ok(LifeLikeRule::new(&[2, 3], &[])));
+    for invalid in ["B3S23", "3/S23", "B3/23", "B3x/S23", "B3/S2,x", "B32,1/S"] {
+        assert!(invalid.parse::<LifeLikeRule>().is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn conway_rule_transitions() {
+    let rule = LifeLikeRule::conway();
+    for n in 0..=8 {
+        let born = n == 3;
+        let survives = n == 2 || n == 3;
+        assert_eq!(rule.apply(Occupancy::Empty, n), Occupancy::from(born));
+        assert_eq!(
+            rule.apply(Occupancy::Occupied, n),
+            Occupancy::from(survives)
+        );
+    }
+}
+
+/// Lattice with the given cells alive
+fn lattice(length: usize, alive: &[[usize; 2]]) -> SquareLattice2D<Occupancy> {
+    let mut state = SquareLattice2D::uniform(length, Occupancy::Empty);
+    for &idx in alive {
+        state[idx] = Occupancy::Occupied;
+    }
+    state
+}
+
+fn alive(state: &SquareLattice2D<Occupancy>) -> Vec<[usize; 2]> {
+    let length = state.length();
+    (0..length)
+        .flat_map(|i| (0..length).map(move |j| [i, j]))
+        .filter(|&idx| state[idx].is_occupied())
+        .collect()
+}
+
+#[test]
+fn blinker_oscillates() {
+    let mut rng = StdRng::seed_from_u64(36);
+    let horizontal = [[2, 1], [2, 2], [2, 3]];
+    let vertical = [[1, 2], [2, 2], [3, 2]];
+    let mut life = LifeLike::new(lattice(5, &horizontal), LifeLikeRule::conway());
+    for t in 1..=4 {
+        life.step(&mut rng);
+        let expected = if t % 2 == 1 { vertical } else { horizontal };
+        assert_eq!(alive(life.state()), expected);
+    }
+    assert_eq!(life.time(), 4.0);
+}
+
+#[test]
+fn block_is_still_across_the_boundary() {
+    let mut rng = StdRng::seed_from_u64(360);
+    let block = [[0, 0], [0, 3], [3, 0], [3, 3]];
+    let mut life = LifeLike::new(lattice(4, &block), LifeLikeRule::conway());
+    life.step(&mut rng);
+    assert_eq!(alive(life.state()), block);
+}
+
+#[test]
+#[should_panic]
+fn rejects_lattices_shorter_than_the_neighborhood() {
+    LifeLike::new(lattice(2, &[]), LifeLikeRule::conway());
+}
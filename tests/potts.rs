@@ -0,0 +1,113 @@
+use artificial_systems::{
+    models::{
+        MonteCarloUpdate,
+        potts::{OrderParameter, Potts},
+    },
+    states::{
+        SiteState, StateMeasurement, lattices::square_lattices::SquareLattice2D, sites::PottsSpin,
+    },
+};
+use rand::{SeedableRng, rngs::StdRng};
+use rand_distr::Distribution;
+
+const MODEL: Potts = Potts {
+    q: 3,
+    coupling: 1.0,
+    beta: 1.0,
+};
+
+#[test]
+fn delta_energy_counts_neighbors() {
+    let mut state = SquareLattice2D::uniform(4, PottsSpin(0));
+    state[[0, 1]] = PottsSpin(1);
+    // Three neighbors agree with the old state and one with the new state
+    assert_eq!(MODEL.delta_energy(&state, [1, 1], PottsSpin(1)), 2.0);
+    // No neighbor agrees with the new state
+    assert_eq!(MODEL.delta_energy(&state, [1, 1], PottsSpin(2)), 3.0);
+    // Flipping the odd spin back aligns it with all four neighbors
+    assert_eq!(MODEL.delta_energy(&state, [0, 1], PottsSpin(0)), -4.0);
+}
+
+#[test]
+fn delta_energy_matches_energy_difference() {
+    let mut rng = StdRng::seed_from_u64(27);
+    let energy = MODEL.energy();
+    let mut state = SquareLattice2D::random(5, &MODEL.states(), &mut rng);
+    let sites = state.site_count() as f64;
+    for _ in 0..100 {
+        let idx = state.sample(&mut rng);
+        let new = MODEL.states().sample(&mut rng);
+        let before = energy.measure(&state);
+        let delta = MODEL.delta_energy(&state, idx, new);
+        state[idx] = new;
+        let after = energy.measure(&state);
+        assert!((sites * (after - before) - delta).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn energy_of_ordered_and_checkerboard_states() {
+    let mut state = SquareLattice2D::uniform(4, PottsSpin(2));
+    // Two bonds per site on the square lattice
+    assert_eq!(MODEL.energy().measure(&state), -2.0);
+    for i in 0..4 {
+        for j in 0..4 {
+            state[[i, j]] = PottsSpin(((i + j) % 2) as u8);
+        }
+    }
+    assert_eq!(MODEL.energy().measure(&state), 0.0);
+}
+
+#[test]
+fn order_parameter_limits() {
+    let mut state = SquareLattice2D::uniform(4, PottsSpin(1));
+    assert_eq!(MODEL.order_parameter().measure(&state), 1.0);
+    // Equal fractions in two of four states
+    for i in 0..2 {
+        for j in 0..4 {
+            state[[i, j]] = PottsSpin(3);
+        }
+    }
+    let half = OrderParameter::new(4).measure(&state);
+    assert!((half - 1.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn order_parameter_rejects_single_state() {
+    OrderParameter::new(1);
+}
+
+#[test]
+fn zero_temperature_metropolis_never_raises_energy() {
+    let mut rng = StdRng::seed_from_u64(270);
+    let model = Potts {
+        beta: f64::INFINITY,
+        ..MODEL
+    };
+    let mut state = SquareLattice2D::random(8, &model.states(), &mut rng);
+    let mut update = model.metropolis();
+    let mut energy = model.energy().measure(&state);
+    for _ in 0..20 {
+        update.step(&mut state, &mut rng);
+        let next = model.energy().measure(&state);
+        assert!(next <= energy);
+        energy = next;
+    }
+}
+
+#[test]
+fn cold_heat_bath_removes_isolated_spin() {
+    let mut rng = StdRng::seed_from_u64(2700);
+    let model = Potts {
+        beta: 50.0,
+        ..MODEL
+    };
+    let mut state = SquareLattice2D::uniform(5, PottsSpin(0));
+    state[[2, 2]] = PottsSpin(1);
+    let mut update = model.heat_bath();
+    for _ in 0..20 {
+        update.step(&mut state, &mut rng);
+    }
+    assert!(state.sites().all(|&s| s == PottsSpin(0)));
+}
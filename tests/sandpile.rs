@@ -0,0 +1,59 @@
+use artificial_systems::{
+    models::sandpile::{Sandpile, SandpileRule},
+    states::{
+        OpenBoundaries, SiteState,
+        lattices::square_lattices::{SquareLattice1D, SquareLattice2D, SquareLattice3D},
+    },
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+const RULES: [SandpileRule; 3] = [SandpileRule::Btw, SandpileRule::Manna, SandpileRule::Oslo];
+
+/// Check that every added grain is either kept or dissipated
+fn assert_conserves<S>(state: S, rule: SandpileRule, rng: &mut StdRng)
+where
+    S: SiteState<Site = u32> + OpenBoundaries,
+{
+    let mut sandpile = Sandpile::new(state, rule);
+    for _ in 0..1000 {
+        let mass = sandpile.mass();
+        let avalanche = sandpile.drive(rng);
+        assert_eq!(mass + 1, sandpile.mass() + avalanche.dissipated as u64);
+    }
+}
+
+#[test]
+fn small_lattices_conserve_grains() {
+    let mut rng = StdRng::seed_from_u64(37);
+    for length in [2, 3] {
+        for rule in RULES {
+            assert_conserves(SquareLattice1D::uniform(length, 0), rule, &mut rng);
+            assert_conserves(SquareLattice2D::uniform(length, 0), rule, &mut rng);
+            assert_conserves(SquareLattice3D::uniform(length, 0), rule, &mut rng);
+        }
+    }
+}
+
+#[test]
+fn toppling_on_two_sites_keeps_inner_grain() {
+    let mut rng = StdRng::seed_from_u64(37);
+    let mut state = SquareLattice1D::uniform(2, 0);
+    state[0] = 1;
+    let mut sandpile = Sandpile::new(state, SandpileRule::Btw);
+    let avalanche = sandpile.add_grain(0, &mut rng);
+    assert_eq!((avalanche.size, avalanche.dissipated), (1, 1));
+    assert_eq!(
+        sandpile.state().sites().copied().collect::<Vec<_>>(),
+        [0, 1]
+    );
+
+    let mut state = SquareLattice2D::uniform(2, 0);
+    state[[0, 0]] = 3;
+    let mut sandpile = Sandpile::new(state, SandpileRule::Btw);
+    let avalanche = sandpile.add_grain([0, 0], &mut rng);
+    assert_eq!((avalanche.size, avalanche.dissipated), (1, 2));
+    assert_eq!(
+        sandpile.state().sites().copied().collect::<Vec<_>>(),
+        [0, 1, 1, 0]
+    );
+}
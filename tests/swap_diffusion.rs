@@ -0,0 +1,78 @@
+use artificial_systems::states::{
+    DriftSwapDiffusion, SiteState,
+    lattices::square_lattices::{SquareLattice1D, SquareLattice2D},
+    sites::Occupancy,
+};
+use rand::{SeedableRng, rngs::StdRng};
+use rand_distr::Bernoulli;
+
+const TRIALS: usize = 1000;
+
+/// Signed displacement between two positions along a periodic axis
+fn displacement(from: usize, to: usize, length: usize) -> f64 {
+    let d = (to + length - from) % length;
+    if d > length / 2 {
+        d as f64 - length as f64
+    } else {
+        d as f64
+    }
+}
+
+/// Linear index of the lone particle
+fn position<S: SiteState<Site = Occupancy>>(state: &S) -> usize {
+    state
+        .sites()
+        .position(|s| s.is_occupied())
+        .expect("Particle must be conserved")
+}
+
+#[test]
+fn drift_moves_lone_particle_1d() {
+    let mut rng = StdRng::seed_from_u64(41);
+    let (length, sweeps, drift) = (61, 20, 0.5);
+    let coin = Bernoulli::new(1.0).unwrap();
+    let mut total = 0.0;
+    for _ in 0..TRIALS {
+        let mut state = SquareLattice1D::uniform(length, Occupancy::Empty);
+        state[length / 2] = Occupancy::Occupied;
+        for _ in 0..sweeps {
+            state.diffuse_with_drift(coin, &drift, |s| s.is_occupied(), &mut rng);
+        }
+        total += displacement(length / 2, position(&state), length);
+    }
+    // One visit per sweep on average, each moving the particle by v on average
+    let mean = total / TRIALS as f64;
+    let expected = drift * sweeps as f64;
+    assert!(
+        (mean - expected).abs() < 0.5,
+        "mean {mean}, expected {expected}"
+    );
+}
+
+#[test]
+fn drift_moves_lone_particle_2d() {
+    let mut rng = StdRng::seed_from_u64(41);
+    let (length, sweeps, drift) = (21, 10, [0.0, 0.5]);
+    let coin = Bernoulli::new(1.0).unwrap();
+    let (mut total_0, mut total_1) = (0.0, 0.0);
+    for _ in 0..TRIALS {
+        let mut state = SquareLattice2D::uniform(length, Occupancy::Empty);
+        let start = state.center();
+        let idx = state.site_index(start);
+        state[idx] = Occupancy::Occupied;
+        for _ in 0..sweeps {
+            state.diffuse_with_drift(coin, &drift, |s| s.is_occupied(), &mut rng);
+        }
+        let end = position(&state);
+        total_0 += displacement(start / length, end / length, length);
+        total_1 += displacement(start % length, end % length, length);
+    }
+    // Each axis is chosen for half of the visits
+    let (mean_0, mean_1) = (total_0 / TRIALS as f64, total_1 / TRIALS as f64);
+    let expected = drift[1] * sweeps as f64 / 2.0;
+    assert!(mean_0.abs() < 0.5, "mean {mean_0}, expected 0");
+    assert!(
+        (mean_1 - expected).abs() < 0.5,
+        "mean {mean_1}, expected {expected}"
+    );
+}
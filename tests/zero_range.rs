@@ -0,0 +1,42 @@
+use artificial_systems::{
+    models::{
+        StochasticProcess,
+        zero_range::{BosonicHopping, ZeroRange},
+    },
+    states::{SiteState, lattices::square_lattices::SquareLattice1D},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+#[test]
+fn slow_particle_hops_at_its_rate() {
+    let mut rng = StdRng::seed_from_u64(45);
+    let (length, steps, trials, rate) = (101, 50, 2000, 0.5);
+    let mut total = 0.0;
+    for _ in 0..trials {
+        let mut state = SquareLattice1D::uniform(length, 0u32);
+        state[length / 2] = 1;
+        let mut process = BosonicHopping::new(state, ZeroRange(|_| rate));
+        let mut position = length / 2;
+        let mut displacement = 0i64;
+        for _ in 0..steps {
+            process.step(&mut rng);
+            let next = process
+                .state()
+                .sites()
+                .position(|&n| n == 1)
+                .expect("Particle must be conserved");
+            // Unwrap the few hops made per unit of time
+            let d = ((next + length - position) % length) as i64;
+            displacement += if d > length as i64 / 2 {
+                d - length as i64
+            } else {
+                d
+            };
+            position = next;
+        }
+        total += (displacement * displacement) as f64;
+    }
+    // The mean squared displacement of a symmetric walk equals the mean number of hops
+    let hops = total / (trials * steps) as f64;
+    assert!((hops - rate).abs() < 0.05, "{hops} hops per unit time");
+}